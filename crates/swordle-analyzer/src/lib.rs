@@ -0,0 +1,222 @@
+//! `swordle-analyzer` reviews completed wordle games guess by guess, comparing each guess against
+//! what an optimal solver would have done.
+
+use std::fmt::Display;
+use swordle::{Dictionary, GameOutcome, Guess, Word};
+use swordle_solver::{best_guess, expected_information};
+
+/// A guess-by-guess breakdown of a game.
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    turns: Box<[Turn]>,
+}
+
+/// The analysis of a single guess.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turn {
+    /// The guess that was made.
+    pub guess: Guess,
+
+    /// How many solutions were still possible before the guess.
+    pub candidates_before: usize,
+
+    /// How many solutions were still possible after the guess.
+    pub candidates_after: usize,
+
+    /// How much information (in bits) the guess was expected to reveal.
+    pub expected_bits: f64,
+
+    /// How much information (in bits) the guess actually revealed.
+    pub actual_bits: f64,
+
+    /// The guess that would have revealed the most information on average, or `None` if no
+    /// solutions were left to reveal anything about.
+    pub best_guess: Option<Word>,
+
+    /// How much information (in bits) the best guess was expected to reveal (0 if there wasn't
+    /// one).
+    pub best_expected_bits: f64,
+}
+
+impl Analysis {
+    /// Analyzes a sequence of guesses, all made against the same solution.
+    #[must_use]
+    pub fn new(guesses: &[Guess]) -> Self {
+        Self::with_dictionary(guesses, &Dictionary::standard())
+    }
+
+    /// Analyzes a sequence of guesses made in a game played with a custom [`Dictionary`].
+    #[must_use]
+    pub fn with_dictionary(guesses: &[Guess], dictionary: &Dictionary) -> Self {
+        let mut candidates = dictionary.solutions().to_vec();
+        let turns = guesses
+            .iter()
+            .map(|guess| {
+                // Guesses that aren't solutions can rule out every candidate, leaving nothing for
+                // the rest of the guesses to be compared against.
+                let best = (!candidates.is_empty()).then(|| best_guess(dictionary, &candidates));
+                let before = candidates.len();
+                let expected_bits = expected_information(*guess.word(), &candidates);
+                let best_expected_bits =
+                    best.map_or(0.0, |best| expected_information(best, &candidates));
+
+                candidates.retain(|c| guess.is_consistent_with(c));
+
+                Turn {
+                    guess: *guess,
+                    candidates_before: before,
+                    candidates_after: candidates.len(),
+                    expected_bits,
+                    actual_bits: bits(before) - bits(candidates.len()),
+                    best_guess: best,
+                    best_expected_bits,
+                }
+            })
+            .collect();
+
+        Analysis { turns }
+    }
+
+    /// Returns the analysis of each guess, in the order they were made.
+    #[must_use]
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+}
+
+impl From<&GameOutcome> for Analysis {
+    /// Analyzes a finished game against the dictionary it was played with.
+    fn from(outcome: &GameOutcome) -> Self {
+        Analysis::with_dictionary(outcome.guesses(), outcome.config().dictionary())
+    }
+}
+
+impl Turn {
+    /// How close the guess came to the best guess's expected information, from 0 to 1, or `None`
+    /// if there was no best guess.
+    #[must_use]
+    pub fn skill(&self) -> Option<f64> {
+        self.best_guess.map(|_| {
+            if self.best_expected_bits > 0.0 {
+                (self.expected_bits / self.best_expected_bits).clamp(0.0, 1.0)
+            } else {
+                1.0
+            }
+        })
+    }
+
+    /// How much more (positive) or less (negative) information the guess revealed than expected.
+    #[must_use]
+    pub fn luck(&self) -> f64 {
+        self.actual_bits - self.expected_bits
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>2}  {:<5}  {:>6}  {:>6}  {:>5}  {:>8}  {:>5}  {:>5}  best",
+            "#", "guess", "before", "after", "bits", "expected", "skill", "luck"
+        )?;
+
+        for (i, turn) in self.turns.iter().enumerate() {
+            let skill = turn
+                .skill()
+                .map_or_else(|| "-".to_owned(), |skill| format!("{:.0}%", skill * 100.0));
+            let best = turn.best_guess.map_or_else(
+                || "-".to_owned(),
+                |best| format!("{best} ({:.2})", turn.best_expected_bits),
+            );
+
+            writeln!(
+                f,
+                "{:>2}  {:<5}  {:>6}  {:>6}  {:>5.2}  {:>8.2}  {skill:>5}  {:>+5.2}  {best}",
+                i + 1,
+                turn.guess.word(),
+                turn.candidates_before,
+                turn.candidates_after,
+                turn.actual_bits,
+                turn.expected_bits,
+                turn.luck(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The information (in bits) needed to single out one of `count` equally likely solutions.
+fn bits(count: usize) -> f64 {
+    u32::try_from(count.max(1)).map_or(f64::INFINITY, |c| f64::from(c).log2())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use swordle::{Game, PlayingGame};
+
+    #[test]
+    fn analysis_tracks_candidates_and_information() {
+        let solution = Word::new("hunky").unwrap();
+        let guesses = ["salet", "courd", "gimpy", "funky", "hunky"]
+            .map(|w| Guess::new(&solution, Word::new(w).unwrap()));
+
+        let analysis = Analysis::from(&GameOutcome::new(solution, Box::new(guesses)));
+        let turns = analysis.turns();
+        assert_eq!(turns.len(), guesses.len());
+        assert_eq!(
+            turns[0].candidates_before,
+            Dictionary::standard().solutions().len()
+        );
+        assert_eq!(turns.last().unwrap().candidates_after, 1);
+
+        for pair in turns.windows(2) {
+            assert_eq!(pair[0].candidates_after, pair[1].candidates_before);
+        }
+
+        for turn in turns {
+            assert!(turn.candidates_after <= turn.candidates_before);
+            assert!(turn.best_expected_bits >= turn.expected_bits);
+            assert!((0.0..=1.0).contains(&turn.skill().unwrap()));
+
+            let actual = bits(turn.candidates_before) - bits(turn.candidates_after);
+            assert!((turn.actual_bits - actual).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn guesses_after_every_candidate_is_ruled_out_have_no_best_guess() {
+        // aahed may be guessed but is never a solution, so nothing is left after salet.
+        let solution = Word::new("aahed").unwrap();
+        let guesses =
+            ["crane", "salet", "hunky"].map(|w| Guess::new(&solution, Word::new(w).unwrap()));
+
+        let analysis = Analysis::new(&guesses);
+        let turns = analysis.turns();
+        assert!(turns[0].best_guess.is_some());
+        assert_eq!(turns[1].candidates_after, 0);
+        assert_eq!(turns[2].best_guess, None);
+        assert_eq!(turns[2].skill(), None);
+        assert!(analysis.to_string().lines().last().unwrap().ends_with('-'));
+    }
+
+    #[test]
+    fn outcomes_are_analyzed_with_their_dictionary() {
+        let dictionary = Arc::new(Dictionary::new(["cats", "cots", "dogs"], ["cuts"]).unwrap());
+        let word = |w| Word::with_dictionary(w, &dictionary).unwrap();
+        let game = PlayingGame::with_solution(Arc::clone(&dictionary), word("dogs")).unwrap();
+        let Ok(Game::Playing(game)) = game.guess(word("cuts")) else {
+            panic!("game should still be playing");
+        };
+        let Ok(Game::Over(outcome)) = game.guess(word("dogs")) else {
+            panic!("game should be over");
+        };
+
+        let turns = Analysis::from(&outcome).turns().to_vec();
+        assert_eq!(turns[0].candidates_before, 3);
+        assert_eq!(turns[0].candidates_after, 1);
+        assert_eq!(turns[1].candidates_after, 1);
+    }
+}
@@ -1,3 +1,49 @@
-fn main() {
-    println!("Hello, World!");
+use std::process::ExitCode;
+use swordle::{Dictionary, Guess, Word};
+use swordle_analyzer::Analysis;
+use swordle_solver::FeedbackMatrix;
+
+const USAGE: &str = "usage: swordle-analyzer [--matrix FILE] <solution> <guess>...";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    // The feedback matrix used to find the best guesses may be cached in a file between runs.
+    if args.first().is_some_and(|arg| arg == "--matrix") {
+        let Some(path) = args.get(1) else {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        };
+        if let Err(error) = FeedbackMatrix::cache_standard(path) {
+            eprintln!("warning: could not cache feedback matrix in {path}: {error}");
+        }
+        args.drain(..2);
+    }
+
+    let words: Result<Vec<Word>, _> = args.iter().map(|w| Word::new(w)).collect();
+    let words = match words {
+        Ok(words) if words.len() >= 2 => words,
+        Ok(_) => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solution = words[0];
+    if !Dictionary::standard().solutions().contains(&solution) {
+        eprintln!("{solution} is never a solution\n{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let guesses: Vec<Guess> = words[1..]
+        .iter()
+        .map(|&word| Guess::new(&solution, word))
+        .collect();
+
+    print!("{}", Analysis::new(&guesses));
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,194 @@
+//! Generates a decision tree strategy file (such as `assets/strategy-hard.txt`) by searching for a
+//! tree over the standard `swordle` dictionary that solves every solution within the maximum number of
+//! guesses.
+//!
+//! ```sh
+//! cargo run --release -p swordle-solver --example generate_strategy -- --hard > assets/strategy-hard.txt
+//! ```
+//!
+//! The first positional argument (if any) overrides the opening guess.
+
+use std::collections::BTreeMap;
+use swordle::{Dictionary, Guess, Placement, Word};
+
+const MAX_GUESSES: usize = 6;
+const DEFAULT_OPENER: &str = "salet";
+
+/// The number of most promising guesses to try at each node before giving up on it.
+const BRANCHING: usize = 12;
+
+struct Node {
+    guess: Word,
+    /// Subtrees keyed by pattern string; `None` when the pattern solves the puzzle.
+    children: BTreeMap<String, Option<Node>>,
+}
+
+struct Search {
+    hard: bool,
+    words: Vec<Word>,
+}
+
+impl Search {
+    fn solve(&self, candidates: &[Word], history: &mut Vec<Guess>) -> Option<Node> {
+        let turn = history.len() + 1;
+        if let [only] = candidates {
+            return Some(Node {
+                guess: *only,
+                children: BTreeMap::from([(pattern_str(&Guess::Correct(*only)), None)]),
+            });
+        }
+
+        if turn >= MAX_GUESSES {
+            return None;
+        }
+
+        let mut options: Vec<_> = self
+            .words
+            .iter()
+            .copied()
+            .filter(|&w| !self.hard || is_hard_valid(w, history))
+            .map(|w| (w, partition(w, candidates)))
+            .filter(|(w, buckets)| buckets.len() > 1 || candidates.contains(w))
+            .filter(|(_, buckets)| turn + 1 < MAX_GUESSES || buckets.values().all(|b| b.len() <= 1))
+            .collect();
+
+        options.sort_by_cached_key(|(w, buckets)| {
+            let spread: usize = buckets.values().map(|b| b.len() * b.len()).sum();
+            (spread, !candidates.contains(w))
+        });
+
+        options
+            .into_iter()
+            .take(BRANCHING)
+            .find_map(|(guess, buckets)| self.expand(guess, buckets, history))
+    }
+
+    fn expand(
+        &self,
+        guess: Word,
+        buckets: BTreeMap<String, Vec<Word>>,
+        history: &mut Vec<Guess>,
+    ) -> Option<Node> {
+        let mut children = BTreeMap::new();
+        for (pattern, bucket) in buckets {
+            if bucket == [guess] {
+                children.insert(pattern, None);
+                continue;
+            }
+
+            history.push(Guess::new(&bucket[0], guess));
+            let child = self.solve(&bucket, history);
+            history.pop();
+
+            children.insert(pattern, Some(child?));
+        }
+
+        Some(Node { guess, children })
+    }
+}
+
+fn main() {
+    let mut hard = false;
+    let mut opener = DEFAULT_OPENER.to_owned();
+    for arg in std::env::args().skip(1) {
+        if arg == "--hard" {
+            hard = true;
+        } else {
+            opener = arg;
+        }
+    }
+
+    let opener = Word::new(&opener).expect("opener must be a valid word");
+    let dictionary = Dictionary::standard();
+    let search = Search {
+        hard,
+        words: dictionary.words().collect(),
+    };
+
+    let buckets = partition(opener, dictionary.solutions());
+    let Some(tree) = search.expand(opener, buckets, &mut Vec::new()) else {
+        eprintln!("no strategy solves every solution within {MAX_GUESSES} guesses");
+        std::process::exit(1);
+    };
+
+    let mut lines = Vec::new();
+    collect_lines(&tree, &mut Vec::new(), &mut lines);
+
+    let mut previous: &[String] = &[];
+    for line in &lines {
+        let shared = line
+            .iter()
+            .zip(previous)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let indent: usize = line[..shared].iter().map(|t| t.len() + 1).sum();
+        println!("{:indent$}{}", "", line[shared..].join(" "));
+        previous = line;
+    }
+}
+
+/// Flattens a tree into its root-to-leaf paths of alternating words and numbered patterns.
+fn collect_lines(node: &Node, path: &mut Vec<String>, lines: &mut Vec<Vec<String>>) {
+    let turn = path.len() / 2 + 1;
+    path.push(node.guess.to_string());
+
+    for (pattern, child) in &node.children {
+        path.push(format!("{pattern}{turn}"));
+        match child {
+            Some(child) => collect_lines(child, path, lines),
+            None => lines.push(path.clone()),
+        }
+        path.pop();
+    }
+
+    path.pop();
+}
+
+fn partition(guess: Word, candidates: &[Word]) -> BTreeMap<String, Vec<Word>> {
+    let mut buckets: BTreeMap<String, Vec<Word>> = BTreeMap::new();
+    for solution in candidates {
+        let pattern = pattern_str(&Guess::new(solution, guess));
+        buckets.entry(pattern).or_default().push(*solution);
+    }
+
+    buckets
+}
+
+fn pattern_str(guess: &Guess) -> String {
+    guess
+        .placements()
+        .iter()
+        .map(|p| match p {
+            Placement::Incorrect => 'B',
+            Placement::Misplaced => 'Y',
+            Placement::Correct => 'G',
+        })
+        .collect()
+}
+
+/// Whether a word uses every hint revealed by previous guesses, i.e. it keeps every correct letter
+/// in place and contains every misplaced letter.
+fn is_hard_valid(word: Word, history: &[Guess]) -> bool {
+    history.iter().all(|prev| {
+        let mut counts = [0usize; 26];
+        for &b in word.as_bytes() {
+            counts[usize::from(b - b'a')] += 1;
+        }
+
+        prev.word()
+            .as_bytes()
+            .iter()
+            .zip(prev.placements())
+            .enumerate()
+            .all(|(i, (&b, &placement))| match placement {
+                Placement::Incorrect => true,
+                Placement::Misplaced | Placement::Correct => {
+                    let count = &mut counts[usize::from(b - b'a')];
+                    let contained = *count > 0;
+                    *count = count.saturating_sub(1);
+                    contained && (placement == Placement::Misplaced || word[i] == b)
+                }
+            })
+    })
+}
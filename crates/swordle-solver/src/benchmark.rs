@@ -0,0 +1,318 @@
+//! Plays a solver against every solution in its dictionary, to validate and compare strategies.
+
+use crate::{Solver, SolverResult};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::num::NonZero;
+use std::panic;
+use std::sync::Arc;
+use std::thread;
+use swordle::game::{GuessError, MAX_GUESSES};
+use swordle::{Game, Guess, PlayingGame, Word};
+
+/// Plays a [`Solver`] against every solution in its dictionary.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    solver: Solver,
+    hard_mode: bool,
+    max_guesses: usize,
+    threads: NonZero<usize>,
+}
+
+impl Benchmark {
+    /// Creates a benchmark that plays games by the classic rules, one at a time.
+    pub fn new(solver: Solver) -> Self {
+        Benchmark {
+            solver,
+            hard_mode: false,
+            max_guesses: MAX_GUESSES,
+            threads: NonZero::<usize>::MIN,
+        }
+    }
+
+    /// Plays games in hard mode, so the solver fails if it doesn't use the hints it's revealed.
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Sets the number of guesses the solver has to find each solution. At least 1 guess is
+    /// always allowed.
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses.max(1);
+        self
+    }
+
+    /// Plays games on `threads` threads at once.
+    pub fn with_threads(mut self, threads: NonZero<usize>) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Plays games on every available thread at once.
+    pub fn parallel(self) -> Self {
+        let threads = thread::available_parallelism().unwrap_or(NonZero::<usize>::MIN);
+        self.with_threads(threads)
+    }
+
+    /// Plays a game against every solution, returning how each went.
+    #[must_use]
+    pub fn run(&self) -> Report {
+        let solutions = self.solver.dictionary.solutions();
+        let per_thread = solutions.len().div_ceil(self.threads.get()).max(1);
+
+        let games: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = solutions
+                .chunks(per_thread)
+                .map(|solutions| {
+                    scope.spawn(move || {
+                        let play = |&solution: &Word| (solution, self.play(solution));
+                        solutions.iter().map(play).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        });
+
+        Report {
+            max_guesses: self.max_guesses,
+            games: games.into_boxed_slice(),
+        }
+    }
+
+    /// Plays a single game, returning the number of guesses the solver took to win it.
+    fn play(&self, solution: Word) -> Result<usize, Failure> {
+        let dictionary = Arc::clone(&self.solver.dictionary);
+        let game = PlayingGame::with_solution(dictionary, solution)
+            .expect("solutions are the same length as the dictionary")
+            .with_hard_mode(self.hard_mode)
+            .with_max_guesses(self.max_guesses);
+
+        let mut game = Game::Playing(game);
+        let mut solver = self.solver.clone();
+        loop {
+            let Game::Playing(playing) = game else {
+                unreachable!("games that are over have already returned");
+            };
+
+            let suggestion = solver.suggest();
+            game = playing
+                .guess(suggestion)
+                .map_err(|rejected| Failure::Rejected {
+                    guess: suggestion,
+                    error: rejected.error,
+                })?;
+
+            if let Game::Over(outcome) = &game {
+                return if outcome.won() {
+                    Ok(outcome.guesses().len())
+                } else {
+                    Err(Failure::Lost)
+                };
+            }
+
+            solver = match solver.report(Guess::new(&solution, suggestion)) {
+                SolverResult::Solving(solver) => solver,
+                SolverResult::Solved { .. } => return Err(Failure::GaveUp),
+            };
+        }
+    }
+}
+
+/// How a solver did against every solution in a [`Benchmark`].
+#[derive(Debug, Clone)]
+pub struct Report {
+    max_guesses: usize,
+    games: Box<[(Word, Result<usize, Failure>)]>,
+}
+
+impl Report {
+    /// Returns each solution, along with the number of guesses taken to find it or why it wasn't
+    /// found.
+    pub fn games(&self) -> &[(Word, Result<usize, Failure>)] {
+        &self.games
+    }
+
+    /// Returns the number of solutions found.
+    #[must_use]
+    pub fn num_solved(&self) -> usize {
+        self.solved().count()
+    }
+
+    /// Returns the number of solutions found in each number of guesses.
+    #[must_use]
+    pub fn distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for (_, guesses) in self.solved() {
+            *distribution.entry(guesses).or_default() += 1;
+        }
+        distribution
+    }
+
+    /// Returns the average number of guesses taken to find a solution, not counting failures.
+    ///
+    /// Returns `None` if no solutions were found.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average(&self) -> Option<f64> {
+        let solved = self.num_solved();
+        let total: usize = self.solved().map(|(_, guesses)| guesses).sum();
+        (solved > 0).then(|| total as f64 / solved as f64)
+    }
+
+    /// Returns the most guesses taken to find a solution, along with every solution that took
+    /// that many.
+    ///
+    /// Returns `None` if no solutions were found.
+    #[must_use]
+    pub fn worst(&self) -> Option<(usize, Vec<Word>)> {
+        let most = self.solved().map(|(_, guesses)| guesses).max()?;
+        let words = self
+            .solved()
+            .filter(|&(_, guesses)| guesses == most)
+            .map(|(word, _)| word)
+            .collect();
+
+        Some((most, words))
+    }
+
+    /// Returns the solutions that weren't found, along with why.
+    pub fn failures(&self) -> impl Iterator<Item = (Word, &Failure)> {
+        self.games
+            .iter()
+            .filter_map(|(word, result)| Some((*word, result.as_ref().err()?)))
+    }
+
+    fn solved(&self) -> impl Iterator<Item = (Word, usize)> {
+        self.games
+            .iter()
+            .filter_map(|(word, result)| Some((*word, *result.as_ref().ok()?)))
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let total = self.games.len();
+        let solved = self.num_solved();
+        write!(f, "Solved {solved}/{total}")?;
+        if let Some(average) = self.average() {
+            write!(f, ", averaging {average:.3} guesses")?;
+        }
+        writeln!(f)?;
+
+        let distribution = self.distribution();
+        let most = distribution.values().copied().max().unwrap_or(0);
+        for guesses in 1..=self.max_guesses {
+            let count = distribution.get(&guesses).copied().unwrap_or(0);
+            // Bars are scaled so the most common guess count is 40 characters wide.
+            let bar = "#".repeat((count * 40).div_ceil(most.max(1)));
+            let line = format!("{guesses:>2}: {count:>5} {bar}");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        if let Some((most, words)) = self.worst() {
+            let words: Vec<&str> = words.iter().map(Word::as_str).collect();
+            writeln!(f, "Worst ({most} guesses): {}", words.join(", "))?;
+        }
+
+        for (word, failure) in self.failures() {
+            writeln!(f, "Failed {word}: {failure}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Why a solver didn't find a solution.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Failure {
+    /// Occurs when the solver used every guess without finding the solution.
+    #[error("ran out of guesses")]
+    Lost,
+
+    /// Occurs when the solver suggests a guess the game doesn't allow, e.g. one that breaks the
+    /// hard mode rules.
+    #[error("guessed {guess}, which was rejected: {error}")]
+    Rejected { guess: Word, error: GuessError },
+
+    /// Occurs when the solver ruled out every solution, i.e. it doesn't agree with the game's
+    /// scoring.
+    #[error("ruled out every solution")]
+    GaveUp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Strategy;
+    use swordle::Dictionary;
+
+    #[test]
+    fn normal_strategy_solves_everything() {
+        let report = Benchmark::new(Solver::new(Strategy::Normal))
+            .parallel()
+            .run();
+        assert_eq!(
+            report.games().len(),
+            Dictionary::standard().solutions().len()
+        );
+        assert_eq!(report.failures().count(), 0);
+        assert_eq!(
+            report.num_solved(),
+            Dictionary::standard().solutions().len()
+        );
+
+        let (most, _) = report.worst().unwrap();
+        assert!(most <= 5);
+        assert_eq!(
+            report.distribution().values().sum::<usize>(),
+            report.num_solved()
+        );
+
+        let average = report.average().unwrap();
+        assert!((3.0..4.0).contains(&average), "{average}");
+    }
+
+    #[test]
+    fn hard_strategy_follows_hard_mode_rules() {
+        let benchmark = Benchmark::new(Solver::new(Strategy::Hard))
+            .with_hard_mode(true)
+            .with_threads(NonZero::new(3).unwrap());
+
+        let report = benchmark.run();
+        assert_eq!(report.failures().count(), 0);
+        assert_eq!(
+            report.num_solved(),
+            Dictionary::standard().solutions().len()
+        );
+    }
+
+    #[test]
+    fn failures_are_reported() {
+        let dictionary = Dictionary::new(["cats", "dogs", "mice", "rats", "bats"], ["tabs"]);
+        let solver = Solver::with_dictionary(Strategy::Entropy, Arc::new(dictionary.unwrap()));
+        let opener = solver.suggest();
+
+        let report = Benchmark::new(solver).with_max_guesses(1).run();
+        assert_eq!(report.num_solved(), 1);
+        assert_eq!(report.worst(), Some((1, vec![opener])));
+        assert_eq!(report.failures().count(), 4);
+        assert!(
+            report
+                .failures()
+                .all(|(word, failure)| word != opener && *failure == Failure::Lost)
+        );
+
+        let text = report.to_string();
+        assert!(
+            text.starts_with("Solved 1/5, averaging 1.000 guesses\n"),
+            "{text}"
+        );
+        assert!(text.contains("Failed mice: ran out of guesses\n"), "{text}");
+    }
+}
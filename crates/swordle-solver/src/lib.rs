@@ -1,7 +1,17 @@
-use swordle::{Guess, Word};
+use std::sync::Arc;
+use swordle::{Dictionary, Guess, Word};
+
+pub mod benchmark;
+pub use benchmark::Benchmark;
+
+pub mod matrix;
+pub use matrix::{FeedbackMatrix, MatrixError};
 
 mod strategy;
-pub use strategy::Strategy;
+pub use strategy::{Strategy, best_guess, expected_information};
+
+pub mod tree;
+pub use tree::{Tree, TreeError};
 
 #[must_use]
 #[derive(Debug, Clone)]
@@ -16,22 +26,64 @@ pub enum SolverResult {
 #[must_use]
 #[derive(Debug, Clone)]
 pub struct Solver {
-    cursor: strategy::Cursor,
+    dictionary: Arc<Dictionary>,
+    progress: strategy::Progress,
     guesses: Vec<Guess>,
 }
 
 impl Solver {
     pub fn new(strategy: Strategy) -> Self {
+        Self::with_dictionary(strategy, Dictionary::standard())
+    }
+
+    /// Creates a solver for games played with a custom [`Dictionary`].
+    ///
+    /// The other strategies follow decision trees built for the standard dictionary, so with any
+    /// other dictionary the solver uses [`Strategy::Entropy`] instead.
+    pub fn with_dictionary(strategy: Strategy, dictionary: Arc<Dictionary>) -> Self {
+        let standard = Dictionary::standard();
+        let strategy = if Arc::ptr_eq(&dictionary, &standard) || *dictionary == *standard {
+            strategy
+        } else {
+            Strategy::Entropy
+        };
+
+        Solver {
+            progress: strategy::Progress::new(strategy, Arc::clone(&dictionary)),
+            dictionary,
+            guesses: Vec::with_capacity(5),
+        }
+    }
+
+    /// Creates a solver that follows a custom decision tree, e.g. one loaded from a file at runtime.
+    pub fn from_tree(tree: impl Into<Arc<Tree>>) -> Self {
         Solver {
-            cursor: strategy::Cursor::new(strategy),
+            dictionary: Dictionary::standard(),
+            progress: strategy::Progress::Tree(strategy::Cursor::new(tree.into())),
             guesses: Vec::with_capacity(5),
         }
     }
 
     pub fn suggest(&self) -> Word {
-        self.cursor.suggest()
+        self.progress.suggest()
     }
 
+    /// Returns the solutions that are still consistent with every guess reported so far.
+    #[must_use]
+    pub fn candidates(&self) -> Box<[Word]> {
+        self.dictionary.candidates(&self.guesses)
+    }
+
+    /// Returns how many solutions are still consistent with every guess reported so far.
+    #[must_use]
+    pub fn num_candidates(&self) -> usize {
+        self.candidates().len()
+    }
+
+    /// Reports the outcome of a guess, which doesn't need to be the word the solver suggested.
+    ///
+    /// Solvers following a decision tree switch to [`Strategy::Entropy`] once a guess leaves the
+    /// tree, since the tree has nothing to say about the words that remain.
     pub fn report(mut self, guess: Guess) -> SolverResult {
         match guess {
             Guess::Correct(word) => {
@@ -41,11 +93,20 @@ impl Solver {
                     guesses: self.guesses.into_boxed_slice(),
                 }
             }
-            Guess::Incorrect(word, placements) => {
-                let cursor = self.cursor.report(placements);
+            Guess::Incorrect(word, _) => {
                 self.guesses.push(guess);
-                if let Some(cursor) = cursor {
-                    self.cursor = cursor;
+
+                let progress = match self.progress {
+                    strategy::Progress::Tree(cursor) if word != cursor.suggest() => None,
+                    progress => progress.report(&guess),
+                };
+                let progress = progress.or_else(|| {
+                    let candidates = self.dictionary.candidates(&self.guesses).into_vec();
+                    strategy::Progress::with_candidates(Arc::clone(&self.dictionary), candidates)
+                });
+
+                if let Some(progress) = progress {
+                    self.progress = progress;
                     SolverResult::Solving(self)
                 } else {
                     SolverResult::Solved {
@@ -61,6 +122,7 @@ impl Solver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use swordle::{Absurdle, Placement};
 
     #[test]
     fn suggest_works() {
@@ -90,4 +152,192 @@ mod tests {
 
         assert_eq!(solution, s);
     }
+
+    #[test]
+    fn candidates_narrow_as_guesses_are_reported() {
+        let mut solver = Solver::new(Strategy::Normal);
+        let solution = Word::new("hunky").unwrap();
+        let mut remaining = solver.num_candidates();
+        assert_eq!(remaining, Dictionary::standard().solutions().len());
+
+        loop {
+            let guess = Guess::new(&solution, solver.suggest());
+            let SolverResult::Solving(s) = solver.report(guess) else {
+                break;
+            };
+
+            solver = s;
+            assert!(solver.candidates().contains(&solution));
+            assert!(solver.num_candidates() < remaining);
+            remaining = solver.num_candidates();
+        }
+    }
+
+    #[test]
+    fn from_tree_follows_custom_tree() {
+        let tree: Tree = "\
+crane BBBBB1 dolly GGGGG2
+      BBBBG1 those GGGGG2
+"
+        .parse()
+        .unwrap();
+
+        for (solution, expected) in [("dolly", ["crane", "dolly"]), ("those", ["crane", "those"])] {
+            let guesses = solve(
+                Solver::from_tree(tree.clone()),
+                Word::new(solution).unwrap(),
+            );
+            let words: Vec<_> = guesses.iter().map(|g| g.word().as_str()).collect();
+            assert_eq!(words, expected);
+        }
+    }
+
+    fn solve(mut solver: Solver, solution: Word) -> Box<[Guess]> {
+        loop {
+            let guess = Guess::new(&solution, solver.suggest());
+            match solver.report(guess) {
+                SolverResult::Solving(s) => solver = s,
+                SolverResult::Solved {
+                    solution: s,
+                    guesses,
+                } => {
+                    assert_eq!(solution, s);
+                    return guesses;
+                }
+            }
+        }
+    }
+
+    fn solve_with(strategy: Strategy, solution: Word) -> Box<[Guess]> {
+        solve(Solver::new(strategy), solution)
+    }
+
+    #[test]
+    fn solvers_adapt_to_guesses_they_did_not_suggest() {
+        let solution = Word::new("hunky").unwrap();
+        let crane = Guess::new(&solution, Word::new("crane").unwrap());
+
+        for strategy in [Strategy::Normal, Strategy::Entropy] {
+            let SolverResult::Solving(solver) = Solver::new(strategy).report(crane) else {
+                panic!("crane isn't the solution");
+            };
+
+            assert!(
+                solver
+                    .candidates()
+                    .iter()
+                    .all(|w| crane.is_consistent_with(w))
+            );
+
+            let guesses = solve(solver, solution);
+            assert_eq!(guesses[0], crane);
+            assert!(guesses.len() <= 6, "{guesses:?}");
+        }
+    }
+
+    #[test]
+    fn normal_strategy_beats_absurdle() {
+        let mut solver = Solver::new(Strategy::Normal);
+        let mut game = Absurdle::new();
+        for _ in 0..5 {
+            let Absurdle::Playing(playing) = game else {
+                break;
+            };
+
+            game = playing.guess(solver.suggest()).unwrap();
+            let guesses = match &game {
+                Absurdle::Playing(playing) => playing.guesses(),
+                Absurdle::Over(outcome) => outcome.guesses(),
+            };
+
+            match solver.report(*guesses.last().unwrap()) {
+                SolverResult::Solving(s) => solver = s,
+                SolverResult::Solved { .. } => break,
+            }
+        }
+
+        let Absurdle::Over(outcome) = game else {
+            panic!("solver took more than 5 guesses");
+        };
+        assert!(outcome.won());
+    }
+
+    #[test]
+    fn normal_strategy_solves_every_solution_within_5_guesses() {
+        for &solution in Dictionary::standard().solutions() {
+            let guesses = solve_with(Strategy::Normal, solution);
+            assert!(
+                guesses.len() <= 5,
+                "{solution} took {} guesses",
+                guesses.len()
+            );
+        }
+    }
+
+    #[test]
+    fn hard_strategy_solves_every_solution_within_6_guesses() {
+        for &solution in Dictionary::standard().solutions() {
+            let guesses = solve_with(Strategy::Hard, solution);
+            assert!(
+                guesses.len() <= 6,
+                "{solution} took {} guesses",
+                guesses.len()
+            );
+        }
+    }
+
+    #[test]
+    fn entropy_strategy_solves_solutions() {
+        for solution in ["hunky", "jazzy", "crane", "mummy", "vivid"] {
+            let solution = Word::new(solution).unwrap();
+            let guesses = solve_with(Strategy::Entropy, solution);
+            assert!(
+                guesses.len() <= 6,
+                "{solution} took {} guesses",
+                guesses.len()
+            );
+        }
+    }
+
+    #[test]
+    fn every_strategy_uses_custom_dictionaries() {
+        let dictionary = Dictionary::new(["cats", "dogs", "mice", "rats", "bats"], ["tabs"]);
+        let dictionary = Arc::new(dictionary.unwrap());
+
+        for strategy in [Strategy::Normal, Strategy::Hard, Strategy::Entropy] {
+            for &solution in dictionary.solutions() {
+                let solver = Solver::with_dictionary(strategy, Arc::clone(&dictionary));
+                assert_eq!(solver.num_candidates(), 5);
+
+                let guesses = solve(solver, solution);
+                assert!(
+                    guesses.len() <= 3,
+                    "{solution} took {} guesses",
+                    guesses.len()
+                );
+                assert!(guesses.iter().all(|g| dictionary.contains(*g.word())));
+            }
+        }
+    }
+
+    #[test]
+    fn hard_strategy_uses_revealed_hints() {
+        for &solution in Dictionary::standard().solutions() {
+            let guesses = solve_with(Strategy::Hard, solution);
+            for (i, prev) in guesses.iter().enumerate() {
+                for next in &guesses[i + 1..] {
+                    for (j, placement) in prev.placements().iter().enumerate() {
+                        let letter = prev.word()[j];
+                        match placement {
+                            Placement::Correct => assert_eq!(next.word()[j], letter),
+                            Placement::Misplaced => {
+                                assert!(next.word().as_bytes().contains(&letter));
+                            }
+                            Placement::Incorrect => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1 +1,170 @@
-fn main() {}
+use std::io::{self, BufRead as _, Write as _};
+use std::process::ExitCode;
+use swordle::{Guess, Word};
+use swordle_solver::{Benchmark, FeedbackMatrix, Solver, SolverResult, Strategy, Tree};
+
+const USAGE: &str = "usage: swordle-solver [--matrix FILE] [--hard | --entropy]
+       swordle-solver [--matrix FILE] benchmark [--hard] [--entropy | --tree FILE] [--parallel]";
+
+const INSTRUCTIONS: &str = "Enter each guess you made and the pattern it revealed (B = gray, \
+Y = yellow, G = green), e.g. `crane BYGBB`, or just the pattern if you guessed the suggestion.";
+
+/// The most candidates to list after each guess; beyond this only the count is shown.
+const MAX_LISTED: usize = 20;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    // The entropy strategy's feedback matrix may be cached in a file between runs.
+    if let ["--matrix", ref rest @ ..] = args[..] {
+        let [path, ref rest @ ..] = rest[..] else {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        };
+        if let Err(error) = FeedbackMatrix::cache_standard(path) {
+            eprintln!("warning: could not cache feedback matrix in {path}: {error}");
+        }
+        args = rest.to_vec();
+    }
+
+    let strategy = match args[..] {
+        ["benchmark", ref options @ ..] => {
+            return benchmark(options).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            });
+        }
+        [] => Strategy::Normal,
+        ["--hard"] => Strategy::Hard,
+        ["--entropy"] => Strategy::Entropy,
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match assist(Solver::new(strategy)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Suggests guesses for a game played elsewhere until it's solved or input ends.
+///
+/// Returns whether the game was solved.
+fn assist(mut solver: Solver) -> io::Result<bool> {
+    println!("{INSTRUCTIONS}\n");
+
+    loop {
+        let suggestion = solver.suggest();
+        println!("Suggestion: {suggestion}");
+        print!("> ");
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        if io::stdin().lock().read_line(&mut input)? == 0 {
+            return Ok(false);
+        }
+
+        let guess = match parse_guess(&input, suggestion) {
+            Ok(guess) => guess,
+            Err(error) => {
+                eprintln!("{error}");
+                continue;
+            }
+        };
+
+        // Check the feedback before reporting it, since a solver can't be rewound.
+        let candidates = solver.candidates();
+        if !guess.is_correct() && !candidates.iter().any(|w| guess.is_consistent_with(w)) {
+            eprintln!("No solutions match that pattern; check it and try again");
+            continue;
+        }
+
+        solver = match solver.report(guess) {
+            SolverResult::Solving(solver) => solver,
+            SolverResult::Solved { solution, guesses } => {
+                let n = guesses.len();
+                println!(
+                    "Solved: {solution} in {n} guess{}",
+                    if n == 1 { "" } else { "es" }
+                );
+                return Ok(true);
+            }
+        };
+
+        let candidates = solver.candidates();
+        match candidates.len() {
+            1 => println!("1 possible solution: {}", candidates[0]),
+            n if n <= MAX_LISTED => {
+                let words: Vec<&str> = candidates.iter().map(Word::as_str).collect();
+                println!("{n} possible solutions: {}", words.join(", "));
+            }
+            n => println!("{n} possible solutions"),
+        }
+    }
+}
+
+/// Plays a strategy against every solution and prints how it did.
+///
+/// With `--hard`, games are played in hard mode (using the hard strategy unless a tree is given).
+fn benchmark(options: &[&str]) -> Result<ExitCode, String> {
+    let (mut hard, mut entropy, mut parallel, mut tree) = (false, false, false, None);
+    let mut options = options.iter();
+    while let Some(&option) = options.next() {
+        match option {
+            "--hard" => hard = true,
+            "--entropy" => entropy = true,
+            "--parallel" => parallel = true,
+            "--tree" => tree = Some(*options.next().ok_or(USAGE)?),
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let solver = match (tree, entropy) {
+        (Some(_), true) => return Err(USAGE.to_owned()),
+        (Some(path), false) => {
+            let tree = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            let tree: Tree = tree.parse().map_err(|e| format!("{path}: {e}"))?;
+            Solver::from_tree(tree)
+        }
+        (None, true) => Solver::new(Strategy::Entropy),
+        (None, false) if hard => Solver::new(Strategy::Hard),
+        (None, false) => Solver::new(Strategy::Normal),
+    };
+
+    let benchmark = Benchmark::new(solver).with_hard_mode(hard);
+    let benchmark = if parallel {
+        benchmark.parallel()
+    } else {
+        benchmark
+    };
+
+    let report = benchmark.run();
+    print!("{report}");
+
+    Ok(if report.failures().next().is_none() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Parses a `word PATTERN` line, or just a `PATTERN` for the suggested word.
+fn parse_guess(input: &str, suggestion: Word) -> Result<Guess, String> {
+    let (word, pattern) = match input.split_whitespace().collect::<Vec<_>>()[..] {
+        [pattern] => (suggestion, pattern),
+        [word, pattern] => (
+            Word::new(word).map_err(|e| format!("{word}: {e}"))?,
+            pattern,
+        ),
+        _ => return Err("Expected a guess and its pattern, e.g. `crane BYGBB`".to_owned()),
+    };
+
+    Guess::from_pattern(word, pattern).map_err(|e| e.to_string())
+}
@@ -0,0 +1,439 @@
+//! A precomputed table of the pattern revealed by every guess against every solution.
+
+use std::fs::File;
+use std::io::{self, Read, Write as _};
+use std::num::NonZero;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use swordle::{Dictionary, Pattern, Word};
+
+/// The longest words a matrix supports, since their patterns must fit in a `u8` code
+/// (`3^5 = 243`). This is shorter than the longest words a [`Dictionary`] may have.
+pub const MAX_ENCODED_LENGTH: usize = 5;
+
+/// Identifies matrix files, and changes whenever their format does.
+const MAGIC: &[u8; 8] = b"swfbmtx1";
+
+/// The standard dictionary's matrix, computed (or [loaded](FeedbackMatrix::cache_standard)) the
+/// first time it's needed.
+static STANDARD: OnceLock<Arc<FeedbackMatrix>> = OnceLock::new();
+
+fn compute_standard() -> Arc<FeedbackMatrix> {
+    let matrix = FeedbackMatrix::new(&Dictionary::standard());
+    Arc::new(matrix.expect("standard dictionary has classic-length words"))
+}
+
+/// The [encoded](Pattern::encode) pattern revealed by every guess in a [`Dictionary`] against
+/// every one of its solutions.
+///
+/// Guesses are identified by their index in [`Dictionary::words`] and solutions by their index in
+/// [`Dictionary::solutions`] (which is the same, since solutions come first).
+///
+/// Computing the matrix scores every pair of words once, after which looking up a pattern is a
+/// single array access. It can be [saved](FeedbackMatrix::save) to disk so later runs can skip
+/// even that.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FeedbackMatrix {
+    word_length: usize,
+    num_solutions: usize,
+    /// Identifies the dictionary the matrix was computed for.
+    fingerprint: u64,
+    /// One row of solution codes per guess.
+    codes: Box<[u8]>,
+}
+
+impl FeedbackMatrix {
+    /// Computes the matrix for a dictionary, using every available thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MatrixError::WordLength`] if the dictionary's words are longer than
+    /// [`MAX_ENCODED_LENGTH`].
+    pub fn new(dictionary: &Dictionary) -> Result<Self, MatrixError> {
+        let word_length = dictionary.word_length();
+        if word_length > MAX_ENCODED_LENGTH {
+            return Err(MatrixError::WordLength(word_length));
+        }
+
+        let words: Vec<Word> = dictionary.words().collect();
+        let solutions = dictionary.solutions();
+        let mut codes = vec![0; words.len() * solutions.len()].into_boxed_slice();
+
+        let threads = thread::available_parallelism().map_or(1, NonZero::get);
+        let rows_per_thread = words.len().div_ceil(threads);
+        thread::scope(|scope| {
+            let chunks = codes.chunks_mut(rows_per_thread * solutions.len());
+            for (guesses, codes) in words.chunks(rows_per_thread).zip(chunks) {
+                scope.spawn(move || {
+                    for (guess, row) in guesses.iter().zip(codes.chunks_mut(solutions.len())) {
+                        for (code, solution) in row.iter_mut().zip(solutions) {
+                            // Patterns of up to 5 letters encode to less than 243.
+                            #[allow(clippy::cast_possible_truncation)]
+                            let encoded = Pattern::score(solution, guess).encode() as u8;
+                            *code = encoded;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(FeedbackMatrix {
+            word_length,
+            num_solutions: solutions.len(),
+            fingerprint: fingerprint(dictionary),
+            codes,
+        })
+    }
+
+    /// Returns the matrix for the [standard dictionary](Dictionary::standard), which is computed
+    /// the first time it's needed and then shared.
+    #[must_use]
+    pub fn standard() -> Arc<FeedbackMatrix> {
+        Arc::clone(STANDARD.get_or_init(compute_standard))
+    }
+
+    /// Loads the [standard](FeedbackMatrix::standard) matrix from a file if it was saved there,
+    /// or computes it and saves it there otherwise, so later runs can skip computing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix had to be computed and can't be saved, although it's still
+    /// used for this run.
+    pub fn cache_standard(path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match Self::load(&Dictionary::standard(), path) {
+            // Matrices for the same dictionary are identical, so one already computed is kept.
+            Ok(matrix) => {
+                let _ = STANDARD.set(Arc::new(matrix));
+                Ok(())
+            }
+            Err(_) => Self::standard().save(path),
+        }
+    }
+
+    /// Loads a dictionary's matrix from a file if it was saved there, or computes it and saves it
+    /// there otherwise (e.g. if the file doesn't exist or was saved for a different dictionary).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MatrixError`] if the matrix can't be computed, or the file can't be written.
+    pub fn cached(dictionary: &Dictionary, path: impl AsRef<Path>) -> Result<Self, MatrixError> {
+        let path = path.as_ref();
+        if let Ok(matrix) = Self::load(dictionary, path) {
+            return Ok(matrix);
+        }
+
+        let matrix = Self::new(dictionary)?;
+        matrix.save(path)?;
+        Ok(matrix)
+    }
+
+    /// Loads a dictionary's matrix from a file written by [`FeedbackMatrix::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MatrixError`] if the file can't be read, isn't a matrix file, or was saved for a
+    /// different dictionary.
+    pub fn load(dictionary: &Dictionary, path: impl AsRef<Path>) -> Result<Self, MatrixError> {
+        let word_length = dictionary.word_length();
+        if word_length > MAX_ENCODED_LENGTH {
+            return Err(MatrixError::WordLength(word_length));
+        }
+
+        let mut file = io::BufReader::new(File::open(path)?);
+        if read_array(&mut file)? != *MAGIC {
+            return Err(MatrixError::Format);
+        }
+
+        let fingerprint = u64::from_le_bytes(read_array(&mut file)?);
+        let num_guesses = u32::from_le_bytes(read_array(&mut file)?);
+        let num_solutions = u32::from_le_bytes(read_array(&mut file)?);
+        let words = dictionary.solutions().len() + dictionary.guesses().len();
+        if fingerprint != self::fingerprint(dictionary)
+            || usize::try_from(num_guesses) != Ok(words)
+            || usize::try_from(num_solutions) != Ok(dictionary.solutions().len())
+        {
+            return Err(MatrixError::Stale);
+        }
+
+        let mut codes = vec![0; words * dictionary.solutions().len()].into_boxed_slice();
+        read_exact(&mut file, &mut codes)?;
+        if file.read(&mut [0])? != 0
+            || codes
+                .iter()
+                .any(|&c| usize::from(c) >= Pattern::count(word_length))
+        {
+            return Err(MatrixError::Format);
+        }
+
+        Ok(FeedbackMatrix {
+            word_length,
+            num_solutions: dictionary.solutions().len(),
+            fingerprint,
+            codes,
+        })
+    }
+
+    /// Saves the matrix to a file, so it can be [loaded](FeedbackMatrix::load) instead of
+    /// computed again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let count = |n: usize| {
+            u32::try_from(n)
+                .map(u32::to_le_bytes)
+                .map_err(io::Error::other)
+        };
+
+        let mut file = io::BufWriter::new(File::create(path)?);
+        file.write_all(MAGIC)?;
+        file.write_all(&self.fingerprint.to_le_bytes())?;
+        file.write_all(&count(self.num_guesses())?)?;
+        file.write_all(&count(self.num_solutions)?)?;
+        file.write_all(&self.codes)?;
+        file.flush()
+    }
+
+    /// Returns whether the matrix was computed for a dictionary.
+    #[must_use]
+    pub fn is_for(&self, dictionary: &Dictionary) -> bool {
+        self.num_solutions == dictionary.solutions().len()
+            && self.fingerprint == fingerprint(dictionary)
+    }
+
+    /// Returns the number of letters in each word.
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Returns the number of guesses, i.e. rows.
+    #[must_use]
+    pub fn num_guesses(&self) -> usize {
+        self.codes.len() / self.num_solutions
+    }
+
+    /// Returns the number of solutions, i.e. columns.
+    #[must_use]
+    pub fn num_solutions(&self) -> usize {
+        self.num_solutions
+    }
+
+    /// Returns the encoded pattern revealed by a guess against a solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either id is out of range.
+    #[must_use]
+    pub fn code(&self, guess: usize, solution: usize) -> u8 {
+        self.row(guess)[solution]
+    }
+
+    /// Returns the pattern revealed by a guess against a solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either id is out of range.
+    pub fn pattern(&self, guess: usize, solution: usize) -> Pattern {
+        Pattern::decode(self.code(guess, solution).into(), self.word_length)
+            .expect("matrix codes are valid patterns")
+    }
+
+    /// Returns the encoded patterns revealed by a guess against every solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is out of range.
+    #[must_use]
+    pub fn row(&self, guess: usize) -> &[u8] {
+        let start = guess * self.num_solutions;
+        &self.codes[start..start + self.num_solutions]
+    }
+
+    /// Returns the expected information (in bits) revealed by a guess, assuming each of the given
+    /// solutions is equally likely. This is the same as [`expected_information`], without
+    /// scoring any words or allocating.
+    ///
+    /// [`expected_information`]: crate::expected_information
+    ///
+    /// # Panics
+    ///
+    /// Panics if any id is out of range.
+    #[must_use]
+    pub fn expected_information(&self, guess: usize, solutions: &[usize]) -> f64 {
+        let row = self.row(guess);
+        let mut counts = [0u32; 243];
+        for &solution in solutions {
+            counts[usize::from(row[solution])] += 1;
+        }
+
+        information(&counts)
+    }
+}
+
+impl std::fmt::Debug for FeedbackMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeedbackMatrix")
+            .field("word_length", &self.word_length)
+            .field("guesses", &self.num_guesses())
+            .field("solutions", &self.num_solutions)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the expected information (in bits) revealed by a guess whose patterns split the
+/// candidates into buckets of the given sizes.
+pub(crate) fn information(counts: &[u32]) -> f64 {
+    let total: f64 = counts.iter().copied().map(f64::from).sum();
+    counts
+        .iter()
+        .copied()
+        .filter(|&count| count > 0)
+        .map(|count| {
+            let count = f64::from(count);
+            count / total * (total / count).log2()
+        })
+        .sum()
+}
+
+/// Reads exactly enough bytes to fill a buffer, treating a file that ends early as invalid.
+fn read_exact(reader: &mut impl Read, buffer: &mut [u8]) -> Result<(), MatrixError> {
+    reader.read_exact(buffer).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => MatrixError::Format,
+        _ => MatrixError::Io(e),
+    })
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], MatrixError> {
+    let mut array = [0; N];
+    read_exact(reader, &mut array)?;
+    Ok(array)
+}
+
+/// Hashes a dictionary's words (with 64-bit FNV-1a), to tell whether a saved matrix belongs to it.
+fn fingerprint(dictionary: &Dictionary) -> u64 {
+    let solutions = dictionary.solutions().iter().map(|w| (w, b'\n'));
+    let guesses = dictionary.guesses().iter().map(|w| (w, b' '));
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for (word, separator) in solutions.chain(guesses) {
+        for &byte in word.as_bytes().iter().chain([&separator]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    hash
+}
+
+/// Errors that may occur when computing or loading a [`FeedbackMatrix`].
+#[derive(Debug, thiserror::Error)]
+pub enum MatrixError {
+    /// Occurs when a dictionary's words are too long for their patterns to fit in a `u8`.
+    #[error("words must have at most {MAX_ENCODED_LENGTH} letters, found {0}")]
+    WordLength(usize),
+
+    /// Occurs when a file isn't a matrix file, or is truncated.
+    #[error("file isn't a feedback matrix")]
+    Format,
+
+    /// Occurs when a matrix file was saved for a different dictionary.
+    #[error("feedback matrix was saved for a different dictionary")]
+    Stale,
+
+    /// Occurs when a matrix file can't be read or written.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_information;
+    use swordle::Guess;
+
+    fn dictionary(solutions: &[&str], guesses: &[&str]) -> Dictionary {
+        Dictionary::new(solutions, guesses).unwrap()
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("swordle-{name}-{}.matrix", std::process::id()))
+    }
+
+    #[test]
+    fn matrix_matches_scoring() {
+        let dictionary = dictionary(&["abbey", "crane", "hunky", "sharp"], &["babes", "eerie"]);
+        let matrix = FeedbackMatrix::new(&dictionary).unwrap();
+        assert_eq!(matrix.num_guesses(), 6);
+        assert_eq!(matrix.num_solutions(), 4);
+        assert!(matrix.is_for(&dictionary));
+
+        for (g, guess) in dictionary.words().enumerate() {
+            for (s, solution) in dictionary.solutions().iter().enumerate() {
+                let expected = Guess::new(solution, guess).pattern();
+                assert_eq!(matrix.pattern(g, s), expected, "{guess} vs {solution}");
+            }
+        }
+
+        let ids = [0, 1, 3];
+        let candidates = ids.map(|id| dictionary.solutions()[id]);
+        for (g, guess) in dictionary.words().enumerate() {
+            let expected = expected_information(guess, &candidates);
+            assert!((matrix.expected_information(g, &ids) - expected).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn standard_matrix_can_be_cached() {
+        let path = temp_path("standard");
+        FeedbackMatrix::cache_standard(&path).unwrap();
+        let saved = FeedbackMatrix::load(&Dictionary::standard(), &path).unwrap();
+        assert_eq!(saved, *FeedbackMatrix::standard());
+
+        FeedbackMatrix::cache_standard(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn matrix_only_supports_short_words() {
+        let dictionary = dictionary(&["planet"], &[]);
+        assert!(matches!(
+            FeedbackMatrix::new(&dictionary),
+            Err(MatrixError::WordLength(6))
+        ));
+    }
+
+    #[test]
+    fn matrix_can_be_saved_and_loaded() {
+        let path = temp_path("saved");
+        let dictionary = dictionary(&["cats", "tack"], &["acts"]);
+        let matrix = FeedbackMatrix::cached(&dictionary, &path).unwrap();
+        assert_eq!(FeedbackMatrix::load(&dictionary, &path).unwrap(), matrix);
+
+        let other = self::dictionary(&["cats", "tack"], &["scat"]);
+        assert!(!matrix.is_for(&other));
+        assert!(matches!(
+            FeedbackMatrix::load(&other, &path),
+            Err(MatrixError::Stale)
+        ));
+
+        let recomputed = FeedbackMatrix::cached(&other, &path).unwrap();
+        assert!(recomputed.is_for(&other));
+        assert_eq!(FeedbackMatrix::load(&other, &path).unwrap(), recomputed);
+
+        std::fs::write(&path, b"not a matrix").unwrap();
+        assert!(matches!(
+            FeedbackMatrix::load(&other, &path),
+            Err(MatrixError::Format)
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            FeedbackMatrix::load(&other, &path),
+            Err(MatrixError::Io(_))
+        ));
+    }
+}
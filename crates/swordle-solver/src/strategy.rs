@@ -1,4 +1,20 @@
-use swordle::{Placement, Word, word::WORD_LENGTH};
+use crate::tree::Tree;
+use std::sync::{Arc, LazyLock};
+use swordle::{Dictionary, Guess, Pattern, Word};
+
+mod entropy;
+use entropy::Entropy;
+pub use entropy::{best_guess, expected_information};
+
+static NORMAL_TREE: LazyLock<Arc<Tree>> = LazyLock::new(|| {
+    let tree = include_str!("../assets/strategy-normal.txt").parse();
+    Arc::new(tree.expect("bundled strategy is a valid tree"))
+});
+
+static HARD_TREE: LazyLock<Arc<Tree>> = LazyLock::new(|| {
+    let tree = include_str!("../assets/strategy-hard.txt").parse();
+    Arc::new(tree.expect("bundled strategy is a valid tree"))
+});
 
 /// The strategy/rule set to use when solving a wordle puzzle.
 #[must_use]
@@ -13,82 +29,94 @@ pub enum Strategy {
     /// subsequent guesses cannot contradict known information.
     /// Using this strategy, the solver can solve any canonical wordle within 6 guesses.
     Hard,
+
+    /// Computes each suggestion live instead of following a precomputed decision tree, picking the
+    /// guess that reveals the most information (on average) about the remaining solutions.
+    /// Slower than the tree strategies, but doesn't rely on any strategy file.
+    Entropy,
 }
 
 impl Strategy {
-    const NORMAL_STR: &str = include_str!("../assets/strategy-normal.txt");
-    const HARD_STR: &str = ""; // TODO: get the asset file
-
-    const fn as_str(self) -> &'static str {
+    /// Returns the decision tree this strategy follows, if it follows one.
+    #[must_use]
+    pub fn tree(self) -> Option<Arc<Tree>> {
         match self {
-            Strategy::Normal => Self::NORMAL_STR,
-            Strategy::Hard => Self::HARD_STR,
+            Strategy::Normal => Some(Arc::clone(&NORMAL_TREE)),
+            Strategy::Hard => Some(Arc::clone(&HARD_TREE)),
+            Strategy::Entropy => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Cursor {
-    strategy: Strategy,
-    row_offset: usize,
-    num_guesses: usize,
+/// The progress a [`Strategy`] has made towards solving a puzzle.
+#[derive(Debug, Clone)]
+pub enum Progress {
+    Tree(Cursor),
+    Entropy(Entropy),
 }
 
-impl Cursor {
-    pub fn new(strategy: Strategy) -> Self {
-        Cursor {
-            strategy,
-            row_offset: 0,
-            num_guesses: 0,
+impl Progress {
+    pub fn new(strategy: Strategy, dictionary: Arc<Dictionary>) -> Self {
+        match strategy.tree() {
+            Some(tree) => Progress::Tree(Cursor::new(tree)),
+            None => Progress::Entropy(Entropy::new(dictionary)),
         }
     }
 
+    /// Creates progress that computes each suggestion live, starting from the solutions that are
+    /// still possible.
+    ///
+    /// Returns `None` if there are no candidates.
+    pub fn with_candidates(dictionary: Arc<Dictionary>, candidates: Vec<Word>) -> Option<Self> {
+        Entropy::with_candidates(dictionary, candidates).map(Progress::Entropy)
+    }
+
     /// Suggests the next word to guess according to the strategy.
-    #[allow(clippy::missing_panics_doc)]
     pub fn suggest(&self) -> Word {
-        self.search_space()
-            .map(|l| &l[self.col_offset()..=self.col_offset() + WORD_LENGTH])
-            .map(Word::new)
-            .find_map(Result::ok)
-            .expect("all suggestions are valid words")
+        match self {
+            Progress::Tree(cursor) => cursor.suggest(),
+            Progress::Entropy(entropy) => entropy.suggest(),
+        }
     }
 
-    /// Reports the placements/outcome of the last suggestion.
+    /// Reports the outcome of the last guess.
     ///
-    /// If the solution was found, consumes the cursor and returns `None`.
-    /// If the solution was not found, advances the cursor and returns `Some`
-    /// containing the new cursor.
-    pub fn report(self, placements: [Placement; WORD_LENGTH]) -> Option<Self> {
-        let search_str: String = placements
-            .iter()
-            .map(|p| match p {
-                Placement::Incorrect => 'B',
-                Placement::Misplaced => 'Y',
-                Placement::Correct => 'G',
-            })
-            .chain((self.num_guesses + 1).to_string().chars().take(1))
-            .collect();
-
-        let advance_by = self
-            .search_space()
-            .enumerate()
-            .find(|(_, l)| l.contains(&search_str))
-            .map_or(0, |(i, _)| i);
-
-        self.search_space().next()?;
-
-        Some(Cursor {
-            strategy: self.strategy,
-            num_guesses: self.num_guesses + 1,
-            row_offset: self.row_offset + advance_by,
-        })
+    /// If the strategy has nothing left to suggest, consumes the progress and returns `None`.
+    pub fn report(self, guess: &Guess) -> Option<Self> {
+        match (self, guess) {
+            (_, Guess::Correct(_)) => None,
+            (Progress::Tree(cursor), guess) => {
+                // Trees only describe classic-length words, so other patterns have no branch.
+                cursor.report(guess.pattern()).map(Progress::Tree)
+            }
+            (Progress::Entropy(entropy), guess) => entropy.report(guess).map(Progress::Entropy),
+        }
     }
+}
+
+/// A position within a decision [`Tree`].
+#[derive(Debug, Clone)]
+pub struct Cursor {
+    tree: Arc<Tree>,
+    node: usize,
+}
 
-    const fn col_offset(&self) -> usize {
-        ((WORD_LENGTH + 1) * 2 + 1) * self.num_guesses
+impl Cursor {
+    pub fn new(tree: Arc<Tree>) -> Self {
+        Cursor { tree, node: 0 }
     }
 
-    fn search_space(&self) -> impl std::iter::Iterator<Item = &'static str> {
-        self.strategy.as_str().lines().skip(self.row_offset)
+    /// Suggests the next word to guess according to the tree.
+    pub fn suggest(&self) -> Word {
+        self.tree.guess(self.node)
+    }
+
+    /// Reports the pattern revealed by the last suggestion.
+    ///
+    /// If the tree has no branch for the pattern, consumes the cursor and returns `None`.
+    /// Otherwise, advances the cursor and returns `Some` containing the new cursor.
+    pub fn report(mut self, pattern: Pattern) -> Option<Self> {
+        self.node = self.tree.child(self.node, pattern)?;
+        Some(self)
     }
 }
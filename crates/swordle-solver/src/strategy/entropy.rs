@@ -0,0 +1,120 @@
+use crate::matrix::{self, FeedbackMatrix};
+use std::sync::{Arc, LazyLock};
+use swordle::{Dictionary, Guess, Pattern, Word};
+
+/// The best first guess with the standard dictionary only depends on its word lists, so it's
+/// computed once and shared.
+static OPENER: LazyLock<Word> = LazyLock::new(|| {
+    let dictionary = Dictionary::standard();
+    search_best_guess(&dictionary, dictionary.solutions())
+});
+
+/// A strategy that tracks the solutions still consistent with the feedback so far and suggests the
+/// guess that maximizes the expected information (in bits) gained about them.
+#[derive(Debug, Clone)]
+pub struct Entropy {
+    dictionary: Arc<Dictionary>,
+    candidates: Vec<Word>,
+}
+
+impl Entropy {
+    pub fn new(dictionary: Arc<Dictionary>) -> Self {
+        Entropy {
+            candidates: dictionary.solutions().to_vec(),
+            dictionary,
+        }
+    }
+
+    /// Creates an entropy strategy that has already narrowed the solutions down to `candidates`.
+    ///
+    /// Returns `None` if there are no candidates.
+    pub fn with_candidates(dictionary: Arc<Dictionary>, candidates: Vec<Word>) -> Option<Self> {
+        (!candidates.is_empty()).then_some(Entropy {
+            dictionary,
+            candidates,
+        })
+    }
+
+    /// Suggests the next word to guess.
+    pub fn suggest(&self) -> Word {
+        best_guess(&self.dictionary, &self.candidates)
+    }
+
+    /// Reports the outcome of a guess, discarding every candidate it rules out.
+    ///
+    /// Returns `None` if no candidates remain.
+    pub fn report(mut self, guess: &Guess) -> Option<Self> {
+        self.candidates
+            .retain(|candidate| guess.is_consistent_with(candidate));
+
+        (!self.candidates.is_empty()).then_some(self)
+    }
+}
+
+/// Picks the word in a dictionary with the highest [expected information](expected_information)
+/// over the remaining candidate solutions, preferring words that could be the solution themselves
+/// when tied.
+///
+/// # Panics
+///
+/// Panics if `candidates` is empty.
+pub fn best_guess(dictionary: &Dictionary, candidates: &[Word]) -> Word {
+    let standard = Dictionary::standard();
+    if std::ptr::eq(dictionary, Arc::as_ptr(&standard)) && candidates == standard.solutions() {
+        *OPENER
+    } else {
+        search_best_guess(dictionary, candidates)
+    }
+}
+
+fn search_best_guess(dictionary: &Dictionary, candidates: &[Word]) -> Word {
+    match candidates {
+        [] => panic!("called best_guess without any candidates"),
+        [first] | [first, _] => return *first,
+        _ => {}
+    }
+
+    // Patterns are looked up rather than scored when the standard dictionary's matrix applies.
+    let standard = Dictionary::standard();
+    let matrix = std::ptr::eq(dictionary, Arc::as_ptr(&standard))
+        .then(FeedbackMatrix::standard)
+        .zip(solution_ids(dictionary, candidates));
+
+    let score = |id: usize, guess: Word| {
+        let bits = match &matrix {
+            Some((matrix, ids)) => matrix.expected_information(id, ids),
+            None => expected_information(guess, candidates),
+        };
+        (bits, candidates.contains(&guess))
+    };
+
+    dictionary
+        .words()
+        .enumerate()
+        .map(|(id, guess)| (guess, score(id, guess)))
+        .max_by(|(_, (a, a_candidate)), (_, (b, b_candidate))| {
+            a.total_cmp(b).then(a_candidate.cmp(b_candidate))
+        })
+        .map(|(guess, _)| guess)
+        .expect("dictionary is not empty")
+}
+
+/// Returns the expected information (in bits) revealed by guessing a word, assuming each of the
+/// remaining candidate solutions is equally likely.
+#[must_use]
+pub fn expected_information(guess: Word, candidates: &[Word]) -> f64 {
+    let mut counts = vec![0u32; Pattern::count(guess.len())];
+    for candidate in candidates {
+        counts[usize::from(Pattern::score(candidate, &guess).encode())] += 1;
+    }
+
+    matrix::information(&counts)
+}
+
+/// Returns the index of each candidate in the dictionary's solutions, or `None` if any isn't one.
+fn solution_ids(dictionary: &Dictionary, candidates: &[Word]) -> Option<Vec<usize>> {
+    candidates
+        .iter()
+        .map(|candidate| dictionary.solutions().binary_search(candidate).ok())
+        .collect()
+}
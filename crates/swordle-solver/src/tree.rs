@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use swordle::{Pattern, Word, word::WORD_LENGTH, word::WordError};
+
+/// A decision tree describing which word to guess next given the placements revealed so far.
+///
+/// Trees are written one root-to-solution path per line, alternating guesses and the placements
+/// (`B`lack, `Y`ellow or `G`reen) they revealed followed by the guess number. The leading part of a
+/// path that it shares with the line above it is left blank:
+///
+/// ```text
+/// salet BBBBB1 courd BBBBB2 nymph BBBBY3 whiff GGGGG4
+///                                 BGYYB3 pygmy GGGGG4
+///                    BBBBG2 vivid GGGGG3
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Tree {
+    /// Every node in the tree; the first is the root.
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    guess: Word,
+    /// Whether guessing this node's word can solve the puzzle.
+    solves: bool,
+    /// The index of the node to visit next for each incorrect outcome of this node's guess.
+    children: BTreeMap<Pattern, usize>,
+}
+
+impl Tree {
+    /// Returns the first word guessed by this tree.
+    pub fn opener(&self) -> Word {
+        self.nodes[0].guess
+    }
+
+    /// Returns the number of distinct solutions this tree can find.
+    #[must_use]
+    pub fn num_solutions(&self) -> usize {
+        self.nodes.iter().filter(|n| n.solves).count()
+    }
+
+    /// Returns the word to guess at a given node.
+    pub(crate) fn guess(&self, node: usize) -> Word {
+        self.nodes[node].guess
+    }
+
+    /// Returns the node to visit after a node's guess revealed the given pattern, if any.
+    pub(crate) fn child(&self, node: usize, pattern: Pattern) -> Option<usize> {
+        self.nodes[node].children.get(&pattern).copied()
+    }
+
+    /// Inserts a root-to-solution path of alternating guesses and patterns into the tree.
+    fn insert(&mut self, line: usize, path: &[(Word, Pattern)]) -> Result<(), TreeError> {
+        let mut node = 0;
+        for (i, &(guess, pattern)) in path.iter().enumerate() {
+            if self.nodes.is_empty() {
+                self.nodes.push(Node::new(guess));
+            }
+
+            if self.nodes[node].guess != guess {
+                return Err(TreeError::Conflict {
+                    line,
+                    expected: self.nodes[node].guess,
+                    found: guess,
+                });
+            }
+
+            let Some(&(next_guess, _)) = path.get(i + 1) else {
+                if !pattern.is_solved() {
+                    return Err(TreeError::Unsolved { line });
+                }
+
+                if std::mem::replace(&mut self.nodes[node].solves, true) {
+                    return Err(TreeError::Duplicate { line });
+                }
+
+                return Ok(());
+            };
+
+            if pattern.is_solved() {
+                return Err(TreeError::Unsolved { line });
+            }
+
+            let num_nodes = self.nodes.len();
+            let child = *self.nodes[node]
+                .children
+                .entry(pattern)
+                .or_insert(num_nodes);
+
+            if child == num_nodes {
+                self.nodes.push(Node::new(next_guess));
+            }
+
+            node = child;
+        }
+
+        Ok(())
+    }
+
+    /// Collects every root-to-solution path below a node, as the tokens they're written with.
+    fn paths(&self, node: usize, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+        let node_ref = &self.nodes[node];
+        let turn = prefix.len() / 2 + 1;
+
+        let mut branches: Vec<_> = node_ref
+            .children
+            .iter()
+            .map(|(pattern, &child)| (pattern.to_string(), Some(child)))
+            .chain(
+                node_ref
+                    .solves
+                    .then(|| (Pattern::solved(WORD_LENGTH).to_string(), None)),
+            )
+            .collect();
+        branches.sort();
+
+        prefix.push(node_ref.guess.to_string());
+        for (pattern, child) in branches {
+            prefix.push(format!("{pattern}{turn}"));
+            match child {
+                Some(child) => self.paths(child, prefix, paths),
+                None => paths.push(prefix.clone()),
+            }
+            prefix.pop();
+        }
+        prefix.pop();
+    }
+}
+
+impl Node {
+    fn new(guess: Word) -> Self {
+        Node {
+            guess,
+            solves: false,
+            children: BTreeMap::new(),
+        }
+    }
+}
+
+impl FromStr for Tree {
+    type Err = TreeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tree = Tree { nodes: Vec::new() };
+        let mut previous: Vec<&str> = Vec::new();
+
+        for (i, text) in s.lines().enumerate() {
+            let line = i + 1;
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            // The blank indentation stands in for the tokens shared with the previous line.
+            let indent = text.len() - text.trim_start().len();
+            let mut width = 0;
+            let shared = previous
+                .iter()
+                .take_while(|token| {
+                    let fits = width < indent;
+                    width += token.len() + 1;
+                    fits
+                })
+                .count();
+
+            let shared_width: usize = previous[..shared].iter().map(|t| t.len() + 1).sum();
+            if shared_width != indent {
+                return Err(TreeError::Indent { line });
+            }
+
+            previous.truncate(shared);
+            previous.extend(text.split_whitespace());
+
+            let path = parse_path(line, &previous)?;
+            tree.insert(line, &path)?;
+        }
+
+        if tree.nodes.is_empty() {
+            return Err(TreeError::Empty);
+        }
+
+        Ok(tree)
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut paths = Vec::new();
+        self.paths(0, &mut Vec::new(), &mut paths);
+
+        let mut previous: &[String] = &[];
+        for path in &paths {
+            let shared = path
+                .iter()
+                .zip(previous)
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            let indent: usize = path[..shared].iter().map(|t| t.len() + 1).sum();
+            writeln!(f, "{:indent$}{}", "", path[shared..].join(" "))?;
+            previous = path;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tree")
+            .field("opener", &self.opener())
+            .field("nodes", &self.nodes.len())
+            .field("solutions", &self.num_solutions())
+            .finish()
+    }
+}
+
+/// Parses the alternating guess and numbered pattern tokens of a line into guess/pattern pairs.
+fn parse_path(line: usize, tokens: &[&str]) -> Result<Vec<(Word, Pattern)>, TreeError> {
+    if !tokens.len().is_multiple_of(2) {
+        return Err(TreeError::Unsolved { line });
+    }
+
+    tokens
+        .chunks_exact(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let guess = Word::new(pair[0]).map_err(|source| TreeError::Word {
+                line,
+                word: pair[0].to_owned(),
+                source,
+            })?;
+
+            let invalid_pattern = || TreeError::Pattern {
+                line,
+                pattern: pair[1].to_owned(),
+            };
+
+            let (pattern, turn) = pair[1]
+                .split_at_checked(WORD_LENGTH)
+                .ok_or_else(invalid_pattern)?;
+
+            if turn.parse() != Ok(i + 1) {
+                return Err(invalid_pattern());
+            }
+
+            let pattern: Pattern = pattern.parse().map_err(|_| invalid_pattern())?;
+            if pattern.len() != WORD_LENGTH {
+                return Err(invalid_pattern());
+            }
+
+            Ok((guess, pattern))
+        })
+        .collect()
+}
+
+/// Errors that may occur when parsing a [`Tree`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TreeError {
+    /// Occurs when the input contains no paths at all.
+    #[error("tree must contain at least one path")]
+    Empty,
+
+    /// Occurs when a line's indentation doesn't line up with the tokens of the line above it.
+    #[error("line {line}: indentation doesn't line up with the previous line")]
+    Indent { line: usize },
+
+    /// Occurs when a guess in a path isn't a valid word.
+    #[error("line {line}: invalid guess {word:?}: {source}")]
+    Word {
+        line: usize,
+        word: String,
+        source: WordError,
+    },
+
+    /// Occurs when a pattern isn't made of exactly 5 `B`/`Y`/`G` letters followed by its guess
+    /// number.
+    #[error("line {line}: invalid pattern {pattern:?}")]
+    Pattern { line: usize, pattern: String },
+
+    /// Occurs when a path doesn't end with a solved pattern, or continues after one.
+    #[error("line {line}: path must end with (and only with) a solved pattern")]
+    Unsolved { line: usize },
+
+    /// Occurs when a path makes a different guess than an earlier path in the same position.
+    #[error("line {line}: expected guess {expected} but found {found}")]
+    Conflict {
+        line: usize,
+        expected: Word,
+        found: Word,
+    },
+
+    /// Occurs when a path repeats an earlier path.
+    #[error("line {line}: duplicate path")]
+    Duplicate { line: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORMAL: &str = include_str!("../assets/strategy-normal.txt");
+    const HARD: &str = include_str!("../assets/strategy-hard.txt");
+
+    #[test]
+    fn bundled_trees_round_trip() {
+        for text in [NORMAL, HARD] {
+            let tree: Tree = text.parse().unwrap();
+            assert_eq!(tree.opener().as_str(), "salet");
+            assert_eq!(tree.num_solutions(), text.lines().count());
+            assert_eq!(tree.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn parse_works_for_small_trees() {
+        let tree: Tree = "\
+salet BBBBB1 courd BBBBB2 nymph GGGGG3
+                   BBBBG2 vivid GGGGG3
+      GGGGG1
+"
+        .parse()
+        .unwrap();
+
+        let courd = tree.child(0, "BBBBB".parse().unwrap()).unwrap();
+        assert_eq!(tree.guess(courd).as_str(), "courd");
+        assert_eq!(tree.num_solutions(), 3);
+        assert!(tree.child(0, Pattern::solved(WORD_LENGTH)).is_none());
+    }
+
+    #[test]
+    fn parse_reports_errors() {
+        let cases: &[(&str, TreeError)] = &[
+            ("", TreeError::Empty),
+            ("salet BBBBB1 courd", TreeError::Unsolved { line: 1 }),
+            ("salet GGGGG1 courd GGGGG2", TreeError::Unsolved { line: 1 }),
+            ("salet BBBBB1 courd BBBBB2", TreeError::Unsolved { line: 1 }),
+            (
+                "salet BBXBB1 courd GGGGG2",
+                TreeError::Pattern {
+                    line: 1,
+                    pattern: "BBXBB1".to_owned(),
+                },
+            ),
+            (
+                "salet BBBBB2 courd GGGGG2",
+                TreeError::Pattern {
+                    line: 1,
+                    pattern: "BBBBB2".to_owned(),
+                },
+            ),
+            (
+                "salet BBBBB1 asdfg GGGGG2",
+                TreeError::Word {
+                    line: 1,
+                    word: "asdfg".to_owned(),
+                    source: WordError::Bank,
+                },
+            ),
+            (
+                "salet BBBBB1 courd GGGGG2\n   BBBBG1 vivid GGGGG2",
+                TreeError::Indent { line: 2 },
+            ),
+            (
+                "salet BBBBB1 courd GGGGG2\n      BBBBB1 vivid GGGGG2",
+                TreeError::Conflict {
+                    line: 2,
+                    expected: Word::new("courd").unwrap(),
+                    found: Word::new("vivid").unwrap(),
+                },
+            ),
+            (
+                "salet BBBBB1 courd GGGGG2\n      BBBBB1 courd GGGGG2",
+                TreeError::Duplicate { line: 2 },
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(input.parse::<Tree>().as_ref(), Err(expected), "{input}");
+        }
+    }
+}
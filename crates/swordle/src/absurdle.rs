@@ -0,0 +1,238 @@
+//! An adversarial game in the style of Absurdle, where the solution isn't chosen in advance.
+//!
+//! Instead, the game keeps every solution that's consistent with the feedback so far, and scores
+//! each guess with whichever pattern keeps the most of them. The solution is only revealed once
+//! a guess leaves it no other choice.
+
+use crate::game::{GameConfig, GameOutcome, GuessError, RejectedGuess, SolutionChoice};
+use crate::{Dictionary, Guess, Pattern, Word, letter_bank::LetterBank};
+use std::sync::Arc;
+
+/// The states an adversarial game may be in.
+#[must_use]
+#[derive(Debug, Clone)]
+pub enum Absurdle {
+    Playing(PlayingAbsurdle),
+    Over(GameOutcome),
+}
+
+impl Absurdle {
+    /// Creates a new game using the [standard](Dictionary::standard) dictionary.
+    pub fn new() -> Self {
+        Self::Playing(PlayingAbsurdle::new())
+    }
+
+    /// Creates a new game where any solution in a [`Dictionary`] may end up being the solution.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
+        Self::Playing(PlayingAbsurdle::with_dictionary(dictionary))
+    }
+}
+
+impl Default for Absurdle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An adversarial game currently in progress.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct PlayingAbsurdle {
+    dictionary: Arc<Dictionary>,
+    /// The solutions consistent with every guess so far, in sorted order.
+    candidates: Vec<Word>,
+    guesses: Vec<Guess>,
+    letter_bank: LetterBank,
+    max_guesses: Option<usize>,
+}
+
+impl PlayingAbsurdle {
+    /// Creates a new game using the [standard](Dictionary::standard) dictionary.
+    pub fn new() -> Self {
+        Self::with_dictionary(Dictionary::standard())
+    }
+
+    /// Creates a new game where any solution in a [`Dictionary`] may end up being the solution.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
+        PlayingAbsurdle {
+            candidates: dictionary.solutions().to_vec(),
+            letter_bank: LetterBank::with_alphabet(dictionary.alphabet()),
+            dictionary,
+            guesses: Vec::new(),
+            max_guesses: None,
+        }
+    }
+
+    /// Limits the number of guesses the player has to find the solution (at least 1).
+    ///
+    /// By default, the player has as many guesses as they need.
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = Some(max_guesses.max(1));
+        self
+    }
+
+    /// Returns the number of guesses the player has to find the solution, or `None` if it's
+    /// unlimited.
+    #[must_use]
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses
+    }
+
+    /// Returns the dictionary the solution will be chosen from.
+    #[must_use]
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Returns the number of letters in the solution (and in every guess).
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.dictionary.word_length()
+    }
+
+    /// Returns the solutions that are still consistent with every guess, in sorted order.
+    pub fn candidates(&self) -> &[Word] {
+        &self.candidates
+    }
+
+    /// Returns the guesses the player has made already.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    #[must_use]
+    pub fn letter_bank(&self) -> &LetterBank {
+        &self.letter_bank
+    }
+
+    /// Makes a guess using a given [`Word`], which is scored with the pattern shared by the most
+    /// remaining candidates. Ties go to the pattern revealing the least, i.e. with the lowest
+    /// [encoding](Pattern::encode), so the guess is only marked correct when it's the last
+    /// candidate.
+    ///
+    /// Returns the resulting [`Absurdle`] state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RejectedGuess`] containing the unchanged game if the word's length differs
+    /// from the game's [word length](Self::word_length).
+    pub fn guess(mut self, word: Word) -> Result<Absurdle, RejectedGuess<Self>> {
+        if word.len() != self.word_length() {
+            let error = GuessError::Length(self.word_length());
+            return Err(RejectedGuess { game: self, error });
+        }
+
+        let mut counts = vec![0usize; Pattern::count(word.len())];
+        for candidate in &self.candidates {
+            counts[usize::from(Pattern::score(candidate, &word).encode())] += 1;
+        }
+
+        // Only strictly larger buckets replace the best so far, so ties go to the lowest code.
+        let mut code = 0;
+        for (c, &count) in counts.iter().enumerate() {
+            if count > counts[code] {
+                code = c;
+            }
+        }
+
+        self.candidates
+            .retain(|candidate| usize::from(Pattern::score(candidate, &word).encode()) == code);
+
+        // Every remaining candidate is scored the same, so any of them gives the guess.
+        let guess = Guess::new(&self.candidates[0], word);
+        self.letter_bank.report_guess(&guess);
+        self.guesses.push(guess);
+
+        if guess.is_correct()
+            || self
+                .max_guesses
+                .is_some_and(|max| self.guesses.len() >= max)
+        {
+            // If the player ran out of guesses, the solution is the first candidate left.
+            let solution = self.candidates[0];
+            let config = GameConfig::with_dictionary(self.dictionary)
+                .with_solution(SolutionChoice::Fixed(solution));
+            let config = match self.max_guesses {
+                Some(max) => config.with_max_guesses(max),
+                None => config.with_unlimited_guesses(),
+            };
+
+            let guesses = self.guesses.into_boxed_slice();
+            let outcome = GameOutcome::with_config(solution, guesses, config);
+            return Ok(Absurdle::Over(outcome));
+        }
+
+        Ok(Absurdle::Playing(self))
+    }
+}
+
+impl Default for PlayingAbsurdle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(s: &str) -> Word {
+        Word::new_no_dict(s, s.len()).unwrap()
+    }
+
+    #[test]
+    fn guesses_keep_the_largest_bucket() {
+        let dictionary =
+            Dictionary::new(["bats", "cats", "rats", "dogs"], std::iter::empty::<&str>()).unwrap();
+        let game = PlayingAbsurdle::with_dictionary(Arc::new(dictionary));
+
+        // Guessing cats scores BGGG against both bats and rats, more than any other pattern.
+        let Absurdle::Playing(game) = game.guess(word("cats")).unwrap() else {
+            panic!("the game shouldn't commit to cats");
+        };
+
+        assert_eq!(game.candidates(), [word("bats"), word("rats")]);
+        assert_eq!(game.guesses()[0].pattern().to_string(), "BGGG");
+    }
+
+    #[test]
+    fn game_only_commits_when_forced() {
+        let dictionary = Dictionary::new(["bats", "cats"], std::iter::empty::<&str>()).unwrap();
+        let game = PlayingAbsurdle::with_dictionary(Arc::new(dictionary));
+
+        let Absurdle::Playing(game) = game.guess(word("cats")).unwrap() else {
+            panic!("the game shouldn't commit to cats while bats is possible");
+        };
+        assert_eq!(game.candidates(), [word("bats")]);
+
+        let Absurdle::Over(outcome) = game.guess(word("bats")).unwrap() else {
+            panic!("bats is the only candidate left");
+        };
+        assert!(outcome.won());
+        assert_eq!(outcome.solution(), &word("bats"));
+        assert_eq!(outcome.guesses().len(), 2);
+        assert_eq!(outcome.config().max_guesses(), None);
+    }
+
+    #[test]
+    fn game_can_be_lost() {
+        let dictionary =
+            Dictionary::new(["bats", "cats", "rats"], std::iter::empty::<&str>()).unwrap();
+        let game = PlayingAbsurdle::with_dictionary(Arc::new(dictionary)).with_max_guesses(1);
+
+        let Absurdle::Over(outcome) = game.guess(word("bats")).unwrap() else {
+            panic!("the game should end after 1 guess");
+        };
+        assert!(!outcome.won());
+        assert!(outcome.guesses()[0].is_consistent_with(outcome.solution()));
+    }
+
+    #[test]
+    fn guesses_must_have_the_right_length() {
+        let dictionary = Dictionary::new(["bats", "cats"], std::iter::empty::<&str>()).unwrap();
+        let game = PlayingAbsurdle::with_dictionary(Arc::new(dictionary));
+
+        let rejected = game.guess(word("crane")).unwrap_err();
+        assert_eq!(rejected.error, GuessError::Length(4));
+    }
+}
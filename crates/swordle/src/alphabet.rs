@@ -0,0 +1,278 @@
+//! The letters words may be made of, so games can be played in languages other than English.
+//!
+//! Every letter is stored as a single byte: its Latin-1 code point, which is its ASCII code for
+//! English letters. Latin-1 covers the accented letters of most Western European languages, and
+//! keeping letters a byte each means words can still be compared position by position.
+//!
+//! Letters outside Latin-1 can't be stored, so languages that need them aren't supported: Polish
+//! (`ł`, `ż`), Czech (`č`, `ř`), Turkish (`ğ`, `ş`) and every language written in another script,
+//! such as Greek or Russian. Parsing words with those letters fails with
+//! [`WordError::Letter`](crate::word::WordError::Letter).
+
+/// A set of lowercase letters that words may be made of, along with a keyboard layout for
+/// typing them.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Alphabet {
+    name: &'static str,
+    letters: &'static str,
+    keyboard: &'static [&'static str],
+}
+
+impl Alphabet {
+    /// The 26 letters of the English alphabet.
+    pub const ENGLISH: &'static Alphabet = &Alphabet {
+        name: "English",
+        letters: "abcdefghijklmnopqrstuvwxyz",
+        keyboard: &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+    };
+
+    /// The English letters along with `ñ`.
+    pub const SPANISH: &'static Alphabet = &Alphabet {
+        name: "Spanish",
+        letters: "abcdefghijklmnñopqrstuvwxyz",
+        keyboard: &["qwertyuiop", "asdfghjklñ", "zxcvbnm"],
+    };
+
+    /// The English letters along with `ä`, `ö`, `ü` and `ß`.
+    pub const GERMAN: &'static Alphabet = &Alphabet {
+        name: "German",
+        letters: "abcdefghijklmnopqrstuvwxyzäöüß",
+        keyboard: &["qwertzuiopü", "asdfghjklöä", "yxcvbnmß"],
+    };
+
+    /// Every letter a word can be made of: the English letters along with every lowercase
+    /// letter in Latin-1.
+    pub const LATIN_1: &'static Alphabet = &Alphabet {
+        name: "Latin-1",
+        letters: "abcdefghijklmnopqrstuvwxyzßàáâãäåæçèéêëìíîïðñòóôõöøùúûüýþÿ",
+        keyboard: &[
+            "qwertyuiop",
+            "asdfghjkl",
+            "zxcvbnm",
+            "àáâãäåæçèéêë",
+            "ìíîïðñòóôõöø",
+            "ùúûüýþÿß",
+        ],
+    };
+
+    /// The alphabets that may be [detected](Self::detect), from smallest to largest.
+    pub const BUILTIN: &'static [&'static Alphabet] =
+        &[Self::ENGLISH, Self::SPANISH, Self::GERMAN, Self::LATIN_1];
+
+    /// Returns the first [builtin](Self::BUILTIN) alphabet that contains every given letter,
+    /// which is [`LATIN_1`](Self::LATIN_1) if no smaller one does.
+    pub fn detect(letters: impl IntoIterator<Item = u8>) -> &'static Alphabet {
+        let mut found = [false; 256];
+        for letter in letters {
+            found[usize::from(letter)] = true;
+        }
+
+        let letters: Vec<u8> = (0..=u8::MAX).filter(|&l| found[usize::from(l)]).collect();
+        Self::BUILTIN
+            .iter()
+            .copied()
+            .find(|alphabet| letters.iter().all(|&l| alphabet.contains(l)))
+            .unwrap_or(Self::LATIN_1)
+    }
+
+    /// Returns the [builtin](Self::BUILTIN) alphabet with a given name, ignoring case.
+    #[must_use]
+    pub fn named(name: &str) -> Option<&'static Alphabet> {
+        Self::BUILTIN
+            .iter()
+            .copied()
+            .find(|alphabet| alphabet.name.eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns every letter in the alphabet, in order.
+    pub fn letters(&self) -> impl Iterator<Item = u8> + use<> {
+        self.letters.chars().filter_map(latin_1)
+    }
+
+    /// Returns the number of letters in the alphabet.
+    #[must_use]
+    pub fn num_letters(&self) -> usize {
+        self.letters.chars().count()
+    }
+
+    /// Returns the rows of a keyboard for typing the alphabet's letters, from top to bottom.
+    #[must_use]
+    pub fn keyboard(&self) -> &'static [&'static str] {
+        self.keyboard
+    }
+
+    /// Returns whether a letter is in the alphabet.
+    #[must_use]
+    pub fn contains(&self, letter: u8) -> bool {
+        self.index_of(letter).is_some()
+    }
+
+    /// Returns the position of a letter in the alphabet, or `None` if it isn't in it.
+    #[must_use]
+    pub fn index_of(&self, letter: u8) -> Option<usize> {
+        self.letters.chars().position(|c| c == char::from(letter))
+    }
+}
+
+/// Returns the uppercase form of a letter for display, or the letter itself if it has no
+/// single-character uppercase form (e.g. `ß`).
+#[must_use]
+pub fn uppercase(letter: char) -> char {
+    let mut upper = letter.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => letter,
+    }
+}
+
+/// Splits text into the letters it's made of, converting each letter to lowercase and combining
+/// any accents typed as separate combining characters (e.g. `n` followed by U+0303) with their
+/// letter, so each letter counts once however it was typed.
+///
+/// Yields `None` for anything that isn't a letter that can be stored.
+pub(crate) fn letters(text: &str) -> impl Iterator<Item = Option<u8>> + '_ {
+    let mut chars = text.chars().peekable();
+    std::iter::from_fn(move || {
+        let mut letter = lowercase(chars.next()?);
+        while let Some(&mark) = chars.peek()
+            && is_combining(mark)
+        {
+            chars.next();
+            letter = letter.and_then(|l| compose(l, mark));
+        }
+
+        Some(
+            letter
+                .and_then(latin_1)
+                .filter(|&l| Alphabet::LATIN_1.contains(l)),
+        )
+    })
+}
+
+fn lowercase(c: char) -> Option<char> {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => Some(lower),
+        _ => None,
+    }
+}
+
+fn latin_1(c: char) -> Option<u8> {
+    u8::try_from(c).ok()
+}
+
+fn is_combining(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// Combines a letter with a combining accent, for every combination with a Latin-1 letter.
+fn compose(letter: char, mark: char) -> Option<char> {
+    const GRAVE: char = '\u{300}';
+    const ACUTE: char = '\u{301}';
+    const CIRCUMFLEX: char = '\u{302}';
+    const TILDE: char = '\u{303}';
+    const DIAERESIS: char = '\u{308}';
+    const RING: char = '\u{30a}';
+    const CEDILLA: char = '\u{327}';
+
+    Some(match (letter, mark) {
+        ('a', GRAVE) => 'à',
+        ('a', ACUTE) => 'á',
+        ('a', CIRCUMFLEX) => 'â',
+        ('a', TILDE) => 'ã',
+        ('a', DIAERESIS) => 'ä',
+        ('a', RING) => 'å',
+        ('c', CEDILLA) => 'ç',
+        ('e', GRAVE) => 'è',
+        ('e', ACUTE) => 'é',
+        ('e', CIRCUMFLEX) => 'ê',
+        ('e', DIAERESIS) => 'ë',
+        ('i', GRAVE) => 'ì',
+        ('i', ACUTE) => 'í',
+        ('i', CIRCUMFLEX) => 'î',
+        ('i', DIAERESIS) => 'ï',
+        ('n', TILDE) => 'ñ',
+        ('o', GRAVE) => 'ò',
+        ('o', ACUTE) => 'ó',
+        ('o', CIRCUMFLEX) => 'ô',
+        ('o', TILDE) => 'õ',
+        ('o', DIAERESIS) => 'ö',
+        ('u', GRAVE) => 'ù',
+        ('u', ACUTE) => 'ú',
+        ('u', CIRCUMFLEX) => 'û',
+        ('u', DIAERESIS) => 'ü',
+        ('y', ACUTE) => 'ý',
+        ('y', DIAERESIS) => 'ÿ',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Vec<Option<char>> {
+        letters(text).map(|l| l.map(char::from)).collect()
+    }
+
+    #[test]
+    fn letters_are_lowercased_and_composed() {
+        assert_eq!(parse("NiÑo"), [Some('n'), Some('i'), Some('ñ'), Some('o')]);
+        assert_eq!(parse("nin\u{303}o"), parse("niño"));
+        assert_eq!(parse("U\u{308}ber"), parse("über"));
+        assert_eq!(parse("Straẞe"), parse("straße"));
+    }
+
+    #[test]
+    fn non_letters_are_rejected() {
+        assert_eq!(parse("a1"), [Some('a'), None]);
+        assert_eq!(parse("\u{3b1}"), [None]);
+        assert_eq!(parse("ł"), [None]);
+        assert_eq!(parse("x\u{303}"), [None]);
+        assert_eq!(parse("÷"), [None]);
+    }
+
+    #[test]
+    fn alphabets_are_detected() {
+        let detect = |word: &str| Alphabet::detect(letters(word).flatten());
+        assert_eq!(detect("crane"), Alphabet::ENGLISH);
+        assert_eq!(detect("niño"), Alphabet::SPANISH);
+        assert_eq!(detect("straße"), Alphabet::GERMAN);
+        assert_eq!(detect("façade"), Alphabet::LATIN_1);
+    }
+
+    #[test]
+    fn alphabets_are_named() {
+        for &alphabet in Alphabet::BUILTIN {
+            assert_eq!(Alphabet::named(alphabet.name()), Some(alphabet));
+        }
+        assert_eq!(Alphabet::named("spanish"), Some(Alphabet::SPANISH));
+        assert_eq!(Alphabet::named("Klingon"), None);
+    }
+
+    #[test]
+    fn keyboards_have_every_letter() {
+        for alphabet in Alphabet::BUILTIN {
+            let keys: String = alphabet.keyboard().concat();
+            assert_eq!(
+                keys.chars().count(),
+                alphabet.num_letters(),
+                "{}",
+                alphabet.name()
+            );
+            assert!(alphabet.letters().all(|l| keys.contains(char::from(l))));
+        }
+    }
+
+    #[test]
+    fn uppercase_works() {
+        assert_eq!(uppercase('a'), 'A');
+        assert_eq!(uppercase('ñ'), 'Ñ');
+        assert_eq!(uppercase('ß'), 'ß');
+    }
+}
@@ -0,0 +1,493 @@
+use clap::{Args, Parser, Subcommand};
+use std::io::{self, BufRead as _, IsTerminal as _, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use swordle::daily::Date;
+use swordle::game::{GameConfig, MAX_GUESSES, SolutionChoice};
+use swordle::share::{ShareStyle, Theme};
+use swordle::stats::{self, History, Record};
+use swordle::{Dictionary, Game, GameOutcome, Guess, Word};
+
+mod tui;
+
+/// Play wordle in the terminal.
+#[derive(Parser)]
+#[command(
+    version,
+    args_conflicts_with_subcommands = true,
+    after_help = "Exit status: 0 if you win, 1 if you lose or quit, 2 on errors.\n\
+                  `check` and `solve` exit with 1 if a word is invalid or no solutions remain."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    play: PlayArgs,
+
+    /// Don't color output (also disabled by setting `NO_COLOR`)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// The history file finished games are recorded in
+    /// [default: `$XDG_DATA_HOME/swordle/history.txt`]
+    #[arg(long, global = true, value_name = "FILE")]
+    stats_file: Option<PathBuf>,
+
+    /// Don't record finished games in the history file
+    #[arg(long, global = true)]
+    no_stats: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play a game with a random (or chosen) solution. This is the default when no subcommand is
+    /// given.
+    Play(PlayArgs),
+
+    /// Play the daily puzzle, which has the same solution for everyone on the same date.
+    Daily(DailyArgs),
+
+    /// List the solutions still possible after some guesses, e.g. `solve salet BBYBG`.
+    Solve(SolveArgs),
+
+    /// Check whether words may be guessed.
+    Check(CheckArgs),
+
+    /// Show the statistics for the games in your history file, or in other players' history
+    /// files to compare them.
+    Stats(StatsArgs),
+}
+
+#[derive(Args)]
+struct PlayArgs {
+    #[command(flatten)]
+    rules: RulesArgs,
+
+    /// The solution to play against
+    #[arg(long, conflicts_with = "seed")]
+    solution: Option<String>,
+
+    /// Seed for choosing the solution, to replay the same game
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Args)]
+struct DailyArgs {
+    #[command(flatten)]
+    rules: RulesArgs,
+
+    /// The date of the puzzle to play, as YYYY-MM-DD [default: today (UTC)]
+    #[arg(long)]
+    date: Option<Date>,
+
+    /// Draw the share grid for a light background
+    #[arg(long)]
+    light: bool,
+
+    /// Draw the share grid with high-contrast colors
+    #[arg(long)]
+    high_contrast: bool,
+}
+
+#[derive(Args)]
+struct SolveArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// Guesses followed by the pattern they revealed (B = gray, Y = yellow, G = green)
+    #[arg(value_name = "GUESS PATTERN")]
+    guesses: Vec<String>,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// The words to check
+    #[arg(required = true)]
+    words: Vec<String>,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    /// History files to show the statistics for instead of your own
+    #[arg(value_name = "FILE")]
+    files: Vec<PathBuf>,
+}
+
+#[derive(Args)]
+struct RulesArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// Require every guess to use the hints revealed so far
+    #[arg(long)]
+    hard: bool,
+
+    /// The number of guesses allowed
+    #[arg(long, default_value_t = MAX_GUESSES, value_parser = parse_max_guesses)]
+    max_guesses: usize,
+
+    /// Allow as many guesses as it takes
+    #[arg(long, conflicts_with = "max_guesses")]
+    unlimited: bool,
+
+    /// Play one line at a time instead of in the full-screen UI
+    #[arg(long)]
+    plain: bool,
+}
+
+#[derive(Args)]
+struct DictionaryArgs {
+    /// The number of letters in each word. The bundled word lists only have 5-letter words, so
+    /// other lengths need a word list of them
+    #[arg(long)]
+    length: Option<usize>,
+
+    /// A word list of solutions to use instead of the bundled word lists
+    #[arg(long = "words", value_name = "FILE")]
+    word_list: Option<PathBuf>,
+
+    /// A word list of extra words that may be guessed but are never solutions
+    #[arg(long = "guesses", value_name = "FILE", requires = "word_list")]
+    guess_list: Option<PathBuf>,
+}
+
+impl DictionaryArgs {
+    fn dictionary(&self) -> Result<Arc<Dictionary>, String> {
+        let dictionary = match (&self.word_list, &self.guess_list) {
+            (Some(words), Some(guesses)) => Dictionary::from_files(words, guesses).map(Arc::new),
+            (Some(words), None) => Dictionary::from_file(words).map(Arc::new),
+            (None, _) => Ok(Dictionary::standard()),
+        };
+        let dictionary = dictionary.map_err(|e| e.to_string())?;
+
+        match self.length {
+            Some(length) if length != dictionary.word_length() => Err(match &self.word_list {
+                Some(words) => format!(
+                    "{} has {}-letter words, not {length}",
+                    words.display(),
+                    dictionary.word_length()
+                ),
+                None => format!(
+                    "No bundled word list has {length}-letter words (use --words FILE with a list of them)"
+                ),
+            }),
+            _ => Ok(dictionary),
+        }
+    }
+}
+
+impl RulesArgs {
+    /// Builds the configuration for a game played by these rules, with its solution chosen by
+    /// `solution` (which may depend on the dictionary).
+    fn config(
+        &self,
+        solution: impl FnOnce(&Dictionary) -> Result<SolutionChoice, String>,
+    ) -> Result<GameConfig, String> {
+        let dictionary = self.dictionary.dictionary()?;
+        let solution = solution(&dictionary)?;
+        let config = GameConfig::with_dictionary(dictionary)
+            .with_solution(solution)
+            .with_hard_mode(self.hard);
+
+        Ok(if self.unlimited {
+            config.with_unlimited_guesses()
+        } else {
+            config.with_max_guesses(self.max_guesses)
+        })
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if cli.no_color {
+        colored::control::set_override(false);
+    }
+    let color = colored::control::SHOULD_COLORIZE.should_colorize();
+    let history = if cli.no_stats {
+        None
+    } else {
+        cli.stats_file.or_else(stats::default_path)
+    };
+
+    let result = match cli.command.unwrap_or(Command::Play(cli.play)) {
+        Command::Play(args) => play(&args, history.as_deref(), color),
+        Command::Daily(args) => daily(&args, history.as_deref(), color),
+        Command::Solve(args) => solve(&args, color),
+        Command::Check(args) => check(&args),
+        Command::Stats(args) => show_stats(&args, history.as_deref()),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        ExitCode::from(2)
+    })
+}
+
+fn play(args: &PlayArgs, history: Option<&Path>, color: bool) -> Result<ExitCode, String> {
+    let config = args
+        .rules
+        .config(|dictionary| match (&args.solution, args.seed) {
+            (Some(solution), _) => Word::with_dictionary(solution, dictionary)
+                .map(SolutionChoice::Fixed)
+                .map_err(|e| e.to_string()),
+            (None, Some(seed)) => Ok(SolutionChoice::Seeded(seed)),
+            (None, None) => Ok(SolutionChoice::Random),
+        })?;
+
+    let game = Game::with_config(config).map_err(|e| e.to_string())?;
+    let outcome = run(game, args.rules.plain, color).map_err(|e| e.to_string())?;
+    if let (Some(outcome), Some(history)) = (&outcome, history) {
+        record(outcome, history);
+    }
+
+    Ok(exit_code(outcome.as_ref()))
+}
+
+fn daily(args: &DailyArgs, history: Option<&Path>, color: bool) -> Result<ExitCode, String> {
+    let date = args.date.unwrap_or_else(Date::today);
+    let config = args.rules.config(|_| Ok(SolutionChoice::Daily(date)))?;
+    let game = Game::with_config(config).map_err(|e| e.to_string())?;
+
+    let outcome = run(game, args.rules.plain, color).map_err(|e| e.to_string())?;
+    if let Some(outcome) = &outcome {
+        let style = ShareStyle {
+            theme: if args.light {
+                Theme::Light
+            } else {
+                Theme::Dark
+            },
+            high_contrast: args.high_contrast,
+        };
+        println!();
+        print!("{}", outcome.share_text(Some(date.puzzle_number()), style));
+
+        if let Some(history) = history {
+            record(outcome, history);
+        }
+    }
+
+    Ok(exit_code(outcome.as_ref()))
+}
+
+fn solve(args: &SolveArgs, color: bool) -> Result<ExitCode, String> {
+    let dictionary = args.dictionary.dictionary()?;
+    if let [.., last] = args.guesses.as_slice()
+        && !args.guesses.len().is_multiple_of(2)
+    {
+        return Err(format!("Expected a pattern after {last}"));
+    }
+
+    let guesses = args
+        .guesses
+        .chunks_exact(2)
+        .map(|pair| parse_guess(&pair[0], &pair[1], &dictionary))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for guess in &guesses {
+        println!("{}", show(guess, color));
+    }
+
+    let candidates = dictionary.candidates(&guesses);
+    println!("\n{} possible solutions:", candidates.len());
+    for candidate in &candidates {
+        println!("{candidate}");
+    }
+
+    Ok(if candidates.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn check(args: &CheckArgs) -> Result<ExitCode, String> {
+    let dictionary = args.dictionary.dictionary()?;
+
+    let mut all_valid = true;
+    for word in &args.words {
+        match Word::with_dictionary(word, &dictionary) {
+            Ok(word) => println!("{word}: valid"),
+            Err(error) => {
+                println!("{word}: {error}");
+                all_valid = false;
+            }
+        }
+    }
+
+    Ok(if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn show_stats(args: &StatsArgs, history: Option<&Path>) -> Result<ExitCode, String> {
+    if args.files.is_empty() {
+        let path = history.ok_or("No history file to show (set --stats-file)")?;
+        let history = History::load(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        print!("{}", history.stats(Date::today()).render(None));
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    for (i, path) in args.files.iter().enumerate() {
+        let history = History::load(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        if i > 0 {
+            println!();
+        }
+        println!("== {} ==", path.display());
+        print!("{}", history.stats(Date::today()).render(None));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Records a finished game in a history file and shows the statistics for games played by the
+/// same rules, with the bar for the game's score highlighted. Games with a chosen solution
+/// aren't recorded, and neither are replays of a recorded daily puzzle or seeded game. Failing
+/// to record a game only prints a warning, since the game itself is already over.
+fn record(outcome: &GameOutcome, path: &Path) {
+    let today = Date::today();
+    let Some(record) = Record::new(outcome, today) else {
+        return;
+    };
+    let history = History::load(path).and_then(|mut history| {
+        if history.contains_game(&record) {
+            println!();
+            println!("This game was already recorded, so it won't count again.");
+        } else {
+            History::append(path, &record)?;
+            history.push(record);
+        }
+        Ok(history)
+    });
+
+    match history {
+        Ok(history) => {
+            let stats =
+                history.stats_with_rules(today, record.max_guesses(), record.is_hard_mode());
+            println!();
+            print!("{}", stats.render(record.score()));
+        }
+        Err(error) => eprintln!(
+            "warning: could not record game in {}: {error}",
+            path.display()
+        ),
+    }
+}
+
+/// Plays a game in the full-screen UI, or one line at a time if that's requested or the
+/// terminal can't be controlled (e.g. input is piped).
+fn run(game: Game, plain: bool, color: bool) -> io::Result<Option<GameOutcome>> {
+    let outcome = if !plain && io::stdin().is_terminal() && io::stdout().is_terminal() {
+        tui::run(game, color)?
+    } else {
+        play_lines(game, color)?
+    };
+
+    if let Some(outcome) = &outcome {
+        print_outcome(outcome, color);
+    }
+
+    Ok(outcome)
+}
+
+/// Plays a game one line of input at a time.
+///
+/// Returns `None` if the input ends before the game is over.
+fn play_lines(mut game: Game, color: bool) -> io::Result<Option<GameOutcome>> {
+    while let Game::Playing(g) = game {
+        println!("----------------------");
+        for guess in g.guesses() {
+            println!("{}", show(guess, color));
+        }
+        print!("Enter guess: ");
+        io::stdout().flush()?;
+
+        let mut input = String::with_capacity(64);
+        if io::stdin().lock().read_line(&mut input)? == 0 {
+            return Ok(None);
+        }
+        println!();
+
+        let guess = match Word::with_dictionary(&input, g.dictionary()) {
+            Ok(guess) => guess,
+            Err(error) => {
+                eprintln!("{error}");
+                game = Game::Playing(g);
+                continue;
+            }
+        };
+
+        game = g.guess(guess).unwrap_or_else(|rejected| {
+            eprintln!("{rejected}");
+            Game::Playing(rejected.game)
+        });
+    }
+
+    let Game::Over(outcome) = game else {
+        unreachable!();
+    };
+
+    Ok(Some(outcome))
+}
+
+fn print_outcome(outcome: &GameOutcome, color: bool) {
+    println!("----------------------");
+    for guess in outcome.guesses() {
+        println!("{}", show(guess, color));
+    }
+
+    if outcome.won() {
+        println!("You won!");
+    } else {
+        println!("You lost! The solution was {}", outcome.solution());
+    }
+}
+
+/// Formats a guess with colored letters, or followed by its pattern if colors are disabled.
+fn show(guess: &Guess, color: bool) -> String {
+    if color {
+        guess.to_string()
+    } else {
+        format!("{} {}", guess.word(), guess.pattern())
+    }
+}
+
+/// Parses a guess and the pattern (e.g. `BBYBG`) it revealed.
+fn parse_guess(word: &str, pattern: &str, dictionary: &Dictionary) -> Result<Guess, String> {
+    let word = Word::with_dictionary(word, dictionary).map_err(|e| format!("{word}: {e}"))?;
+    Guess::from_pattern(word, pattern).map_err(|e| format!("{word} {pattern}: {e}"))
+}
+
+fn parse_max_guesses(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("Must allow at least 1 guess".to_owned()),
+        Ok(max_guesses) => Ok(max_guesses),
+        Err(error) => Err(format!("{error}")),
+    }
+}
+
+fn exit_code(outcome: Option<&GameOutcome>) -> ExitCode {
+    if outcome.is_some_and(GameOutcome::won) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory as _;
+
+    #[test]
+    fn cli_is_valid() {
+        Cli::command().debug_assert();
+    }
+}
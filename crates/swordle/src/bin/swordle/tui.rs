@@ -0,0 +1,340 @@
+//! A full-screen terminal UI, with a board of tiles and an on-screen keyboard.
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::collections::HashMap;
+use std::io::{self, Write as _};
+use std::thread;
+use std::time::Duration;
+use swordle::game::{GameEvent, MAX_GUESSES};
+use swordle::letter_bank::LetterBank;
+use swordle::{Alphabet, Game, GameOutcome, Placement, PlayingGame, Word, alphabet};
+
+/// How long each tile takes to flip over when a guess is revealed.
+const REVEAL_DELAY: Duration = Duration::from_millis(250);
+
+/// The line the first row of the board is drawn on, below the title.
+const BOARD_TOP: usize = 3;
+
+/// Each tile is drawn as a letter padded by a space on either side.
+const TILE_WIDTH: u16 = 3;
+
+/// Plays a game in the terminal's alternate screen until it's over or the player quits.
+///
+/// Without `color`, tiles are marked with brackets instead: `[C]`orrect, `(M)`isplaced.
+///
+/// Returns the outcome of the game, or `None` if the player quit before it was over.
+pub fn run(mut game: Game, color: bool) -> io::Result<Option<GameOutcome>> {
+    let (rows, letter_bank) = match &game {
+        Game::Playing(playing) => (playing.max_guesses(), playing.letter_bank().clone()),
+        Game::Over(outcome) => {
+            let mut letter_bank =
+                LetterBank::with_alphabet(outcome.config().dictionary().alphabet());
+            for guess in outcome.guesses() {
+                letter_bank.report_guess(guess);
+            }
+            (Some(outcome.guesses().len()), letter_bank)
+        }
+    };
+
+    let keyboard = letter_bank
+        .alphabet()
+        .letters()
+        .filter_map(|letter| Some((letter, letter_bank.get(&letter)?)))
+        .collect();
+
+    let _screen = Screen::enter()?;
+    let mut ui = Ui {
+        rows,
+        keyboard,
+        color,
+        input: String::new(),
+        message: String::new(),
+    };
+
+    loop {
+        ui.draw(&game, None)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        // Any key exits a finished game, keeping its outcome.
+        game = match game {
+            Game::Over(outcome) => return Ok(Some(outcome)),
+            Game::Playing(_) if is_quit(key) => return Ok(None),
+            Game::Playing(playing) => ui.handle(playing, key.code)?,
+        };
+    }
+}
+
+/// Puts the terminal into full-screen raw mode, restoring it when dropped (even on panic).
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // There's nothing useful to do if restoring the terminal fails.
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+struct Ui {
+    /// The number of rows on the board, i.e. the game's maximum number of guesses, or `None` if
+    /// the board grows a row at a time.
+    rows: Option<usize>,
+    /// The placement shown on the keyboard for each letter, updated from the game's events once
+    /// the tiles of the guess that revealed it have been.
+    keyboard: HashMap<u8, Placement>,
+    color: bool,
+    /// The letters typed so far for the next guess.
+    input: String,
+    /// A message shown below the board, e.g. why a guess was rejected.
+    message: String,
+}
+
+impl Ui {
+    fn handle(&mut self, game: PlayingGame, code: KeyCode) -> io::Result<Game> {
+        match code {
+            KeyCode::Char(c) if self.input.chars().count() < game.word_length() => {
+                if let Some(letter) = typed_letter(c, game.dictionary().alphabet()) {
+                    self.input.push(char::from(letter));
+                    self.message.clear();
+                }
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.message.clear();
+            }
+            KeyCode::Enter => return self.submit(game),
+            _ => {}
+        }
+
+        Ok(Game::Playing(game))
+    }
+
+    /// Guesses the typed word, revealing its tiles one by one if it's accepted.
+    fn submit(&mut self, game: PlayingGame) -> io::Result<Game> {
+        let word = match Word::with_dictionary(&self.input, game.dictionary()) {
+            Ok(word) => word,
+            Err(error) => {
+                self.message = error.to_string();
+                return Ok(Game::Playing(game));
+            }
+        };
+
+        let mut events = Vec::new();
+        let game = match game.guess_observed(word, &mut events) {
+            Ok(game) => game,
+            Err(rejected) => {
+                self.message = rejected.error.to_string();
+                return Ok(Game::Playing(rejected.game));
+            }
+        };
+
+        self.input.clear();
+        for revealed in 1..=word.len() {
+            self.draw(&game, Some(revealed))?;
+            thread::sleep(REVEAL_DELAY);
+        }
+
+        for event in events {
+            if let GameEvent::LetterChanged {
+                letter, placement, ..
+            } = event
+                && let Ok(letter) = u8::try_from(letter)
+            {
+                self.keyboard.insert(letter, placement);
+            }
+        }
+
+        if let Game::Over(outcome) = &game {
+            self.message = if outcome.won() {
+                "You won! Press any key to exit".to_owned()
+            } else {
+                let solution = outcome.solution().as_str().to_uppercase();
+                format!("The solution was {solution}. Press any key to exit")
+            };
+        }
+
+        Ok(game)
+    }
+
+    /// Draws the whole screen. While a guess is being revealed, only the first `revealing` tiles
+    /// of the latest guess are colored, and the keyboard doesn't show what it revealed yet.
+    fn draw(&self, game: &Game, revealing: Option<usize>) -> io::Result<()> {
+        let (guesses, word_length, alphabet) = match game {
+            Game::Playing(g) => (g.guesses(), g.word_length(), g.dictionary().alphabet()),
+            Game::Over(o) => (
+                o.guesses(),
+                o.solution().len(),
+                o.config().dictionary().alphabet(),
+            ),
+        };
+
+        let (width, height) = terminal::size()?;
+        let center = |content_width: u16| width.saturating_sub(content_width) / 2;
+
+        let mut stdout = io::stdout().lock();
+        queue!(stdout, Clear(ClearType::All))?;
+
+        let title = "S W O R D L E";
+        queue!(
+            stdout,
+            MoveTo(center(str_width(title)), 1),
+            SetAttribute(Attribute::Bold),
+            Print(title),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        // Boards without a limit always have a row for the next guess.
+        let rows = self
+            .rows
+            .unwrap_or_else(|| (guesses.len() + 1).max(MAX_GUESSES));
+
+        // Boards taller than the terminal are scrolled to show the latest guesses, along with the
+        // row for the next one while the game is being played.
+        let keyboard = alphabet.keyboard();
+        let spare_lines = usize::from(height).saturating_sub(BOARD_TOP + 2 * keyboard.len() + 5);
+        let visible = rows.min(spare_lines / 2).max(1);
+        let end = match game {
+            Game::Playing(_) if revealing.is_none() => guesses.len() + 1,
+            _ => guesses.len(),
+        };
+        let first = end.min(rows).saturating_sub(visible);
+
+        let board_width = u16::try_from(word_length).unwrap_or(u16::MAX) * (TILE_WIDTH + 1);
+        for (line, row) in (first..first + visible).enumerate() {
+            let y = u16::try_from(BOARD_TOP + line * 2).unwrap_or(u16::MAX);
+            queue!(stdout, MoveTo(center(board_width), y))?;
+
+            let tiles: Vec<(u8, Option<Placement>)> = match guesses.get(row) {
+                Some(guess) => {
+                    let shown = match revealing {
+                        Some(revealed) if row + 1 == guesses.len() => revealed,
+                        _ => word_length,
+                    };
+
+                    guess
+                        .word()
+                        .as_bytes()
+                        .iter()
+                        .zip(guess.placements())
+                        .enumerate()
+                        .map(|(i, (&letter, &p))| (letter, (i < shown).then_some(p)))
+                        .collect()
+                }
+                None if row == guesses.len() && revealing.is_none() => {
+                    let typed = self.input.chars().map(|c| u8::try_from(c).ok());
+                    typed
+                        .chain(std::iter::repeat(None))
+                        .take(word_length)
+                        .map(|letter| (letter.unwrap_or(b' '), None))
+                        .collect()
+                }
+                None => vec![(b' ', None); word_length],
+            };
+
+            for (letter, placement) in tiles {
+                draw_tile(&mut stdout, letter, placement, self.color)?;
+                queue!(stdout, Print(" "))?;
+            }
+        }
+
+        let keyboard_top = u16::try_from(BOARD_TOP + 1 + visible * 2).unwrap_or(u16::MAX);
+        for (i, row) in (0u16..).zip(keyboard) {
+            let row_width = str_width(row) * (TILE_WIDTH + 1);
+            queue!(stdout, MoveTo(center(row_width), keyboard_top + i * 2))?;
+            for letter in row.chars().filter_map(|c| u8::try_from(c).ok()) {
+                let placement = self.keyboard.get(&letter).copied();
+                draw_tile(&mut stdout, letter, placement, self.color)?;
+                queue!(stdout, Print(" "))?;
+            }
+        }
+
+        let message_top = keyboard_top + u16::try_from(keyboard.len() * 2).unwrap_or(0) + 1;
+        queue!(
+            stdout,
+            MoveTo(center(str_width(&self.message)), message_top),
+            Print(&self.message),
+        )?;
+
+        let help = "Enter: guess   Backspace: delete   Esc: quit";
+        queue!(stdout, MoveTo(center(str_width(help)), message_top + 2))?;
+        queue!(
+            stdout,
+            SetAttribute(Attribute::Dim),
+            Print(help),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        stdout.flush()
+    }
+}
+
+/// Draws a single letter tile, colored (or bracketed) by its placement if it's known.
+fn draw_tile(
+    stdout: &mut impl io::Write,
+    letter: u8,
+    placement: Option<Placement>,
+    color: bool,
+) -> io::Result<()> {
+    let letter = alphabet::uppercase(char::from(letter));
+    let (left, right) = match (placement, color) {
+        (None, _) if letter == ' ' => ('_', '_'),
+        (Some(Placement::Correct), false) => ('[', ']'),
+        (Some(Placement::Misplaced), false) => ('(', ')'),
+        _ => (' ', ' '),
+    };
+
+    let background = match placement {
+        Some(Placement::Correct) => Color::DarkGreen,
+        Some(Placement::Misplaced) => Color::DarkYellow,
+        Some(Placement::Incorrect) => Color::DarkGrey,
+        None => Color::Reset,
+    };
+
+    if color {
+        queue!(stdout, SetBackgroundColor(background))?;
+    }
+
+    let dim = !color && placement == Some(Placement::Incorrect);
+    queue!(
+        stdout,
+        SetAttribute(if dim { Attribute::Dim } else { Attribute::Bold }),
+        Print(format!("{left}{letter}{right}")),
+        SetAttribute(Attribute::Reset),
+        ResetColor,
+    )
+}
+
+/// Returns the letter a typed character stands for, if it's in the alphabet.
+fn typed_letter(c: char, alphabet: &Alphabet) -> Option<u8> {
+    let mut lower = c.to_lowercase();
+    let letter = u8::try_from(lower.next()?).ok()?;
+    (lower.next().is_none() && alphabet.contains(letter)).then_some(letter)
+}
+
+fn is_quit(key: KeyEvent) -> bool {
+    key.code == KeyCode::Esc
+        || (key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c'))
+}
+
+fn str_width(s: &str) -> u16 {
+    u16::try_from(s.chars().count()).unwrap_or(u16::MAX)
+}
@@ -0,0 +1,239 @@
+//! Daily puzzles, where everyone playing on the same date gets the same solution.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The date of the first daily puzzle (the original Wordle's puzzle #0).
+pub const EPOCH: Date = Date {
+    year: 2021,
+    month: 6,
+    day: 19,
+};
+
+/// A calendar date (in the proleptic Gregorian calendar) that selects a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a date from a year, month (1-12) and day of the month (1-31).
+    ///
+    /// Returns `None` if the day doesn't exist in that month.
+    #[must_use]
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Date { year, month, day })
+    }
+
+    /// Returns the current date in UTC.
+    #[must_use]
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self::from_days(i64::try_from(seconds / 86_400).unwrap_or(i64::MAX))
+    }
+
+    #[must_use]
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    #[must_use]
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    #[must_use]
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the number of the daily puzzle for this date, counting from 0 on [`EPOCH`].
+    ///
+    /// Dates before the epoch have negative puzzle numbers.
+    #[must_use]
+    pub fn puzzle_number(self) -> i64 {
+        self.days() - EPOCH.days()
+    }
+
+    /// Returns the number of days between the unix epoch (1970-01-01) and this date.
+    fn days(self) -> i64 {
+        // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates the date a number of days after the unix epoch (1970-01-01).
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_days(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = (shifted_month + 2) % 12 + 1;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = DateError;
+
+    /// Parses a date written as `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or(DateError::Format);
+
+        let year = next()?.parse().map_err(|_| DateError::Format)?;
+        let month = next()?.parse().map_err(|_| DateError::Format)?;
+        let day = next()?.parse().map_err(|_| DateError::Format)?;
+
+        Date::new(year, month, day).ok_or(DateError::Invalid)
+    }
+}
+
+/// Returns the index of the solution to play on a given date, out of `num_solutions` solutions.
+///
+/// Consecutive dates step through the solutions with a fixed stride that shares no factors with
+/// `num_solutions`, so every solution is played exactly once before any is repeated, without
+/// playing them in (alphabetical) order.
+///
+/// # Panics
+///
+/// Panics if `num_solutions` is 0.
+#[must_use]
+pub fn puzzle_index(date: Date, num_solutions: usize) -> usize {
+    assert!(
+        num_solutions > 0,
+        "called puzzle_index without any solutions"
+    );
+
+    let len = num_solutions as u128;
+    let mut stride = len * 5 / 8 + 1;
+    while gcd(stride, len) != 1 {
+        stride += 1;
+    }
+
+    let position = i128::from(date.puzzle_number()).rem_euclid(len.cast_signed());
+    let index = position.cast_unsigned() * stride % len;
+    usize::try_from(index).expect("index is less than num_solutions")
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Errors that may occur when parsing a [`Date`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DateError {
+    /// Occurs when a date isn't written as `YYYY-MM-DD`.
+    #[error("Date must be written as YYYY-MM-DD")]
+    Format,
+
+    /// Occurs when a date doesn't exist, e.g. `2023-02-30`.
+    #[error("Date does not exist")]
+    Invalid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn new_validates_dates() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2023, 4, 31).is_none());
+        assert!(Date::new(2023, 13, 1).is_none());
+        assert!(Date::new(2023, 1, 0).is_none());
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let date: Date = "2022-02-01".parse().unwrap();
+        assert_eq!(date, Date::new(2022, 2, 1).unwrap());
+        assert_eq!(date.to_string(), "2022-02-01");
+
+        assert_eq!("2022-02".parse::<Date>(), Err(DateError::Format));
+        assert_eq!("2022-ab-01".parse::<Date>(), Err(DateError::Format));
+        assert_eq!("2022-02-30".parse::<Date>(), Err(DateError::Invalid));
+    }
+
+    #[test]
+    fn puzzle_numbers_count_days_from_epoch() {
+        assert_eq!(EPOCH.puzzle_number(), 0);
+        assert_eq!(Date::new(2021, 6, 20).unwrap().puzzle_number(), 1);
+        assert_eq!(Date::new(2022, 1, 1).unwrap().puzzle_number(), 196);
+        assert_eq!(Date::new(2021, 6, 18).unwrap().puzzle_number(), -1);
+    }
+
+    #[test]
+    fn days_round_trip() {
+        for days in [-800_000, -1, 0, 1, 18_797, 19_000, 2_000_000] {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1).unwrap());
+        assert_eq!(Date::from_days(EPOCH.days()), EPOCH);
+    }
+
+    #[test]
+    fn puzzle_index_plays_every_solution_once_per_cycle() {
+        for num_solutions in [1, 2, 12, 2315] {
+            let indices: HashSet<usize> = (0..num_solutions)
+                .map(|n| {
+                    let date = Date::from_days(EPOCH.days() + i64::try_from(n).unwrap());
+                    puzzle_index(date, num_solutions)
+                })
+                .collect();
+
+            assert_eq!(indices.len(), num_solutions);
+        }
+
+        let today = Date::today();
+        assert_eq!(puzzle_index(today, 2315), puzzle_index(today, 2315));
+    }
+}
@@ -0,0 +1,408 @@
+use crate::daily::{self, Date};
+use crate::word::{Word, WordError, bank};
+use crate::{Alphabet, Guess, Knowledge};
+use rand::Rng;
+use rand::seq::IndexedRandom as _;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The words that may be guessed in a game, some of which may also be chosen as its solution.
+///
+/// Every word in a dictionary has the same length.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    word_length: usize,
+    alphabet: &'static Alphabet,
+    solutions: Box<[Word]>,
+    guesses: Box<[Word]>,
+}
+
+impl Dictionary {
+    /// Creates a dictionary from a list of solutions and a list of additional words that may be
+    /// guessed but are never solutions.
+    ///
+    /// Words are trimmed and converted to lowercase, and the dictionary's word length is the
+    /// length of its first solution. Its alphabet is the smallest [builtin](Alphabet::BUILTIN)
+    /// alphabet with every letter in its words.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryError`] if there are no solutions, or if any word has a letter that
+    /// can't be stored or has a different length than the first solution.
+    pub fn new<S, G>(solutions: S, guesses: G) -> Result<Self, DictionaryError>
+    where
+        S: IntoIterator<Item: AsRef<str>>,
+        G: IntoIterator<Item: AsRef<str>>,
+    {
+        let mut dictionary = Self::with_alphabet(Alphabet::LATIN_1, solutions, guesses)?;
+        let words = dictionary.solutions.iter().chain(&dictionary.guesses);
+        let alphabet = Alphabet::detect(words.flat_map(|w| w.as_bytes().iter().copied()));
+        dictionary.alphabet = alphabet;
+        Ok(dictionary)
+    }
+
+    /// Creates a dictionary from a list of solutions and a list of additional words that may be
+    /// guessed but are never solutions, where every word is made of letters in an [`Alphabet`].
+    ///
+    /// Words are trimmed and converted to lowercase, and the dictionary's word length is the
+    /// length of its first solution.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryError`] if there are no solutions, or if any word has a letter
+    /// that isn't in the alphabet or has a different length than the first solution.
+    pub fn with_alphabet<S, G>(
+        alphabet: &'static Alphabet,
+        solutions: S,
+        guesses: G,
+    ) -> Result<Self, DictionaryError>
+    where
+        S: IntoIterator<Item: AsRef<str>>,
+        G: IntoIterator<Item: AsRef<str>>,
+    {
+        let mut solutions = solutions.into_iter().peekable();
+        let first = solutions.peek().ok_or(DictionaryError::Empty)?.as_ref();
+        let parse = |word: &str, length| {
+            Word::parse(word, length, alphabet).map_err(|source| DictionaryError::Word {
+                word: word.to_owned(),
+                source,
+            })
+        };
+        let word_length = parse(first, None)?.len();
+
+        let mut solutions: Vec<Word> = solutions
+            .map(|w| parse(w.as_ref(), Some(word_length)))
+            .collect::<Result<_, _>>()?;
+        solutions.sort_unstable();
+        solutions.dedup();
+
+        let mut guesses: Vec<Word> = guesses
+            .into_iter()
+            .map(|w| parse(w.as_ref(), Some(word_length)))
+            .collect::<Result<_, _>>()?;
+        guesses.sort_unstable();
+        guesses.dedup();
+        guesses.retain(|w| solutions.binary_search(w).is_err());
+
+        Ok(Dictionary {
+            word_length,
+            alphabet,
+            solutions: solutions.into_boxed_slice(),
+            guesses: guesses.into_boxed_slice(),
+        })
+    }
+
+    /// Loads a dictionary from a word list file where every word is a possible solution.
+    ///
+    /// Word lists have one word per line. Blank lines and lines starting with `#` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryError`] if the file can't be read or doesn't make a valid
+    /// dictionary (see [`Dictionary::new`]).
+    pub fn from_file(solutions: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+        let solutions = std::fs::read_to_string(solutions)?;
+        Self::new(word_list(&solutions), std::iter::empty::<&str>())
+    }
+
+    /// Loads a dictionary from a word list file of solutions and a word list file of additional
+    /// words that may be guessed but are never solutions.
+    ///
+    /// Word lists have one word per line. Blank lines and lines starting with `#` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DictionaryError`] if either file can't be read or they don't make a valid
+    /// dictionary (see [`Dictionary::new`]).
+    pub fn from_files(
+        solutions: impl AsRef<Path>,
+        guesses: impl AsRef<Path>,
+    ) -> Result<Self, DictionaryError> {
+        let solutions = std::fs::read_to_string(solutions)?;
+        let guesses = std::fs::read_to_string(guesses)?;
+        Self::new(word_list(&solutions), word_list(&guesses))
+    }
+
+    /// Returns the bundled dictionary of English words used by the classic game.
+    #[must_use]
+    pub fn standard() -> Arc<Dictionary> {
+        Arc::clone(&bank::STANDARD)
+    }
+
+    /// Returns the alphabet every word in the dictionary is made of.
+    #[must_use]
+    pub fn alphabet(&self) -> &'static Alphabet {
+        self.alphabet
+    }
+
+    /// Returns the number of letters in every word in the dictionary.
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Returns every word that may be a solution, in sorted order.
+    pub fn solutions(&self) -> &[Word] {
+        &self.solutions
+    }
+
+    /// Returns every word that may be guessed but is never a solution, in sorted order.
+    pub fn guesses(&self) -> &[Word] {
+        &self.guesses
+    }
+
+    /// Returns every word that may be guessed, solutions first.
+    pub fn words(&self) -> impl Iterator<Item = Word> {
+        self.solutions.iter().chain(&self.guesses).copied()
+    }
+
+    /// Returns whether a word may be guessed.
+    #[must_use]
+    pub fn contains(&self, word: Word) -> bool {
+        [&self.solutions, &self.guesses]
+            .iter()
+            .any(|c| c.binary_search(&word).is_ok())
+    }
+
+    /// Returns the solutions that are consistent with every given guess, i.e. the words that
+    /// could still be the solution.
+    #[must_use]
+    pub fn candidates(&self, guesses: &[Guess]) -> Box<[Word]> {
+        let knowledge = Knowledge::from_guesses(guesses);
+        self.solutions
+            .iter()
+            .filter(|solution| knowledge.matches(solution))
+            .copied()
+            .collect()
+    }
+
+    /// Returns a random solution.
+    pub fn random_solution(&self) -> Word {
+        self.random_solution_with(&mut rand::rng())
+    }
+
+    /// Returns a random solution chosen using a given random number generator.
+    ///
+    /// Given a seeded generator, the same solution is chosen every time.
+    pub fn random_solution_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Word {
+        let Some(solution) = self.solutions.choose(rng).copied() else {
+            unreachable!("Dictionary should always have a solution")
+        };
+
+        solution
+    }
+
+    /// Returns the solution of the daily puzzle for a given date.
+    ///
+    /// Every solution is used once before any is repeated (see [`daily::puzzle_index`]).
+    pub fn daily_solution(&self, date: Date) -> Word {
+        self.solutions[daily::puzzle_index(date, self.solutions.len())]
+    }
+}
+
+impl std::fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dictionary")
+            .field("word_length", &self.word_length)
+            .field("alphabet", &self.alphabet.name())
+            .field("solutions", &self.solutions.len())
+            .field("guesses", &self.guesses.len())
+            .finish()
+    }
+}
+
+/// Returns the words in a word list, skipping blank lines and `#` comments.
+fn word_list(s: &str) -> impl Iterator<Item = &str> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Errors that may occur when creating a [`Dictionary`].
+#[derive(Debug, thiserror::Error)]
+pub enum DictionaryError {
+    /// Occurs when a dictionary would have no solutions.
+    #[error("Dictionary must have at least one solution")]
+    Empty,
+
+    /// Occurs when a word in a dictionary isn't valid.
+    #[error("Invalid word {word:?} in dictionary: {source}")]
+    Word { word: String, source: WordError },
+
+    /// Occurs when a word list file can't be read.
+    #[error("Could not read word list: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::WORD_LENGTH;
+
+    fn strs(words: &[Word]) -> Vec<&str> {
+        words.iter().map(Word::as_str).collect()
+    }
+
+    #[test]
+    fn new_works() {
+        let dictionary = Dictionary::new(["Cats", "dogs ", "cats"], ["tack", "dogs"]).unwrap();
+
+        assert_eq!(dictionary.word_length(), 4);
+        assert_eq!(strs(dictionary.solutions()), ["cats", "dogs"]);
+        assert_eq!(strs(dictionary.guesses()), ["tack"]);
+        assert_eq!(dictionary.words().count(), 3);
+
+        for word in ["cats", "dogs", "tack"] {
+            assert!(dictionary.contains(Word::with_dictionary(word, &dictionary).unwrap()));
+        }
+    }
+
+    #[test]
+    fn new_reports_errors() {
+        assert!(matches!(
+            Dictionary::new(std::iter::empty::<&str>(), ["tack"]),
+            Err(DictionaryError::Empty)
+        ));
+
+        assert!(matches!(
+            Dictionary::new(["cats", "mice"], ["horse"]),
+            Err(DictionaryError::Word {
+                source: WordError::Length(4),
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            Dictionary::new(["pineapple"], ["tack"]),
+            Err(DictionaryError::Word {
+                source: WordError::TooLong(8),
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            Dictionary::new([" "], ["tack"]),
+            Err(DictionaryError::Word {
+                source: WordError::Empty,
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            Dictionary::new(["cats", "m1ce"], ["tack"]),
+            Err(DictionaryError::Word {
+                source: WordError::Letter,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn alphabets_are_detected() {
+        let dictionary = Dictionary::new(["Niño", "casa"], ["peña"]).unwrap();
+        assert_eq!(dictionary.alphabet(), Alphabet::SPANISH);
+        assert_eq!(dictionary.word_length(), 4);
+        assert_eq!(strs(dictionary.solutions()), ["casa", "niño"]);
+
+        let dictionary = Dictionary::new(["straße", "bücher"], std::iter::empty::<&str>());
+        assert_eq!(dictionary.unwrap().alphabet(), Alphabet::GERMAN);
+
+        let dictionary = Dictionary::new(["cats"], std::iter::empty::<&str>()).unwrap();
+        assert_eq!(dictionary.alphabet(), Alphabet::ENGLISH);
+    }
+
+    #[test]
+    fn with_alphabet_rejects_other_letters() {
+        let dictionary = Dictionary::with_alphabet(Alphabet::SPANISH, ["niño"], ["nin\u{303}a"]);
+        assert_eq!(strs(dictionary.unwrap().guesses()), ["niña"]);
+
+        assert!(matches!(
+            Dictionary::with_alphabet(Alphabet::ENGLISH, ["cats", "niño"], ["tack"]),
+            Err(DictionaryError::Word {
+                source: WordError::Letter,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn from_files_works() {
+        let dir = std::env::temp_dir().join(format!("swordle-dictionary-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let solutions = dir.join("solutions.txt");
+        let guesses = dir.join("guesses.txt");
+        std::fs::write(&solutions, "# Spanish\nperro\n\nGATOS\n").unwrap();
+        std::fs::write(&guesses, "raton\n").unwrap();
+
+        let dictionary = Dictionary::from_files(&solutions, &guesses).unwrap();
+        assert_eq!(strs(dictionary.solutions()), ["gatos", "perro"]);
+        assert_eq!(strs(dictionary.guesses()), ["raton"]);
+
+        let dictionary = Dictionary::from_file(&solutions).unwrap();
+        assert!(dictionary.guesses().is_empty());
+
+        assert!(matches!(
+            Dictionary::from_file(dir.join("missing.txt")),
+            Err(DictionaryError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn solutions_can_be_chosen_deterministically() {
+        use rand::{SeedableRng as _, rngs::StdRng};
+
+        let dictionary = Dictionary::standard();
+        let seeded = || dictionary.random_solution_with(&mut StdRng::seed_from_u64(42));
+        assert_eq!(seeded(), seeded());
+
+        let date = Date::new(2024, 3, 1).unwrap();
+        let next = Date::new(2024, 3, 2).unwrap();
+        assert_eq!(
+            dictionary.daily_solution(date),
+            dictionary.daily_solution(date)
+        );
+        assert_ne!(
+            dictionary.daily_solution(date),
+            dictionary.daily_solution(next)
+        );
+    }
+
+    #[test]
+    fn standard_dictionary_only_has_words_of_its_length() {
+        let dictionary = Dictionary::standard();
+        assert_eq!(dictionary.word_length(), WORD_LENGTH);
+        assert!(dictionary.words().all(|w| w.len() == WORD_LENGTH));
+    }
+
+    #[test]
+    fn candidates_works() {
+        let dictionary = Dictionary::standard();
+        assert_eq!(
+            dictionary.candidates(&[]).len(),
+            dictionary.solutions().len()
+        );
+
+        let solution = Word::new_no_dict("hunky", WORD_LENGTH).unwrap();
+        let guesses = ["salet", "courd"]
+            .map(|w| Guess::new(&solution, Word::new_no_dict(w, WORD_LENGTH).unwrap()));
+
+        let after_one = dictionary.candidates(&guesses[..1]);
+        assert!(after_one.contains(&solution));
+        assert!(after_one.len() < dictionary.solutions().len());
+        assert!(!after_one.iter().any(|w| w.as_bytes().contains(&b's')));
+
+        let after_two = dictionary.candidates(&guesses);
+        assert!(after_two.contains(&solution));
+        assert!(after_two.len() < after_one.len());
+        assert!(
+            after_two
+                .iter()
+                .all(|w| w.as_bytes().contains(&b'u') && w[2] != b'u')
+        );
+
+        let solved = dictionary.candidates(&[Guess::Correct(solution)]);
+        assert_eq!(*solved, [solution]);
+    }
+}
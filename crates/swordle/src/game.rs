@@ -1,12 +1,26 @@
+use crate::word::WordError;
+use crate::{Dictionary, Word, daily::Date};
+use rand::Rng;
+use std::sync::Arc;
+
+/// The number of guesses a player has to find the solution.
+pub const MAX_GUESSES: usize = 6;
+
+mod config;
+pub use config::{GameConfig, SolutionChoice};
+
+mod events;
+pub use events::{GameEvent, GameObserver};
+
 mod outcome;
 pub use outcome::GameOutcome;
 
 mod playing;
-pub use playing::PlayingGame;
+pub use playing::{GuessError, PlayingGame, RejectedGuess};
 
 /// The states a wordle game may be in.
 #[must_use]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub enum Game {
     Playing(PlayingGame),
     Over(GameOutcome),
@@ -16,6 +30,42 @@ impl Game {
     pub fn new() -> Self {
         Self::Playing(PlayingGame::new())
     }
+
+    /// Creates a new game from a [`Dictionary`], with a random solution from it.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
+        Self::Playing(PlayingGame::with_dictionary(dictionary))
+    }
+
+    /// Creates a new game from a [`Dictionary`], with a solution chosen from it using a given
+    /// random number generator.
+    pub fn with_rng<R: Rng + ?Sized>(dictionary: Arc<Dictionary>, rng: &mut R) -> Self {
+        Self::Playing(PlayingGame::with_rng(dictionary, rng))
+    }
+
+    /// Creates the daily puzzle for a given date.
+    pub fn daily(dictionary: Arc<Dictionary>, date: Date) -> Self {
+        Self::Playing(PlayingGame::daily(dictionary, date))
+    }
+
+    /// Creates a new game with a given solution, using a [`Dictionary`] for guesses.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if the solution's length differs from the dictionary's
+    /// word length.
+    pub fn with_solution(dictionary: Arc<Dictionary>, solution: Word) -> Result<Self, WordError> {
+        PlayingGame::with_solution(dictionary, solution).map(Self::Playing)
+    }
+
+    /// Creates a new game played by the rules in a [`GameConfig`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if the configuration has a [fixed](SolutionChoice::Fixed)
+    /// solution whose length differs from the dictionary's word length.
+    pub fn with_config(config: GameConfig) -> Result<Self, WordError> {
+        PlayingGame::with_config(config).map(Self::Playing)
+    }
 }
 
 impl Default for Game {
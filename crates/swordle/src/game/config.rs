@@ -0,0 +1,130 @@
+use super::MAX_GUESSES;
+use crate::{Dictionary, Word, daily::Date};
+use std::sync::Arc;
+
+/// The rules a game is played by, along with the dictionary it's played with and how its
+/// solution is chosen.
+///
+/// The default configuration plays the classic game: a random solution from the
+/// [standard](Dictionary::standard) dictionary, [`MAX_GUESSES`] guesses and no hard mode.
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameConfig {
+    dictionary: Arc<Dictionary>,
+    solution: SolutionChoice,
+    hard_mode: bool,
+    max_guesses: Option<usize>,
+}
+
+impl GameConfig {
+    /// Creates a configuration for the classic game.
+    pub fn new() -> Self {
+        Self::with_dictionary(Dictionary::standard())
+    }
+
+    /// Creates a configuration for the classic game, played with a [`Dictionary`].
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
+        GameConfig {
+            dictionary,
+            solution: SolutionChoice::Random,
+            hard_mode: false,
+            max_guesses: Some(MAX_GUESSES),
+        }
+    }
+
+    /// Creates the configuration a finished game is assumed to have been played with when all
+    /// that's known is its solution: the classic rules, played with the standard dictionary.
+    pub(crate) fn for_solution(solution: Word) -> Self {
+        Self::new().with_solution(SolutionChoice::Fixed(solution))
+    }
+
+    /// Sets how the solution is chosen. Defaults to [`SolutionChoice::Random`].
+    pub fn with_solution(mut self, solution: SolutionChoice) -> Self {
+        self.solution = solution;
+        self
+    }
+
+    /// Turns hard mode on or off.
+    ///
+    /// In hard mode, every guess must use the hints revealed by previous guesses: letters
+    /// revealed as correct must stay in place, and letters revealed as misplaced must be reused.
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Sets the number of guesses the player has to find the solution (at least 1).
+    ///
+    /// Defaults to [`MAX_GUESSES`].
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = Some(max_guesses.max(1));
+        self
+    }
+
+    /// Lets the player make as many guesses as they need to find the solution.
+    pub fn with_unlimited_guesses(mut self) -> Self {
+        self.max_guesses = None;
+        self
+    }
+
+    /// Returns the dictionary the game is played with.
+    #[must_use]
+    pub fn dictionary(&self) -> &Arc<Dictionary> {
+        &self.dictionary
+    }
+
+    /// Returns the number of letters in the solution (and in every guess).
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.dictionary.word_length()
+    }
+
+    /// Returns how the solution is chosen.
+    #[must_use]
+    pub fn solution(&self) -> SolutionChoice {
+        self.solution
+    }
+
+    /// Returns whether the game is played in hard mode.
+    #[must_use]
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Returns the number of guesses the player has to find the solution, or `None` if it's
+    /// unlimited.
+    #[must_use]
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses
+    }
+
+    /// Returns whether a game is over once it has a number of guesses, whether or not the last
+    /// one was correct.
+    pub(crate) fn is_out_of_guesses(&self, guesses: usize) -> bool {
+        self.max_guesses.is_some_and(|max| guesses >= max)
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a game's solution is chosen from its dictionary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SolutionChoice {
+    /// A random solution.
+    #[default]
+    Random,
+
+    /// A solution chosen using a random number generator with a given seed, so every game with
+    /// the same seed (and dictionary) has the same solution.
+    Seeded(u64),
+
+    /// The daily puzzle for a given date.
+    Daily(Date),
+
+    /// A given solution, which doesn't need to be in the dictionary.
+    Fixed(Word),
+}
@@ -0,0 +1,51 @@
+use super::GuessError;
+use crate::{Guess, Placement, Word};
+
+/// Something that happened while making a guess, reported to a [`GameObserver`] by
+/// [`PlayingGame::guess_observed`](super::PlayingGame::guess_observed).
+///
+/// An accepted guess is reported as [`Guessed`](Self::Guessed), followed by a
+/// [`LetterChanged`](Self::LetterChanged) for every letter it revealed something new about, and
+/// then [`Won`](Self::Won) or [`Lost`](Self::Lost) if it ended the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A guess was accepted and scored.
+    Guessed(Guess),
+
+    /// A word was rejected, leaving the game unchanged.
+    Rejected { word: Word, error: GuessError },
+
+    /// A letter's best known placement in the [`LetterBank`](crate::letter_bank::LetterBank)
+    /// changed, from `previous` (`None` if it hadn't been guessed) to `placement`.
+    LetterChanged {
+        letter: char,
+        previous: Option<Placement>,
+        placement: Placement,
+    },
+
+    /// The solution was found with a number of guesses.
+    Won { guesses: usize },
+
+    /// The player ran out of guesses without finding the solution.
+    Lost { solution: Word },
+}
+
+/// Receives the [`GameEvent`]s that happen while making guesses, e.g. to drive animations or
+/// logging without comparing game states.
+///
+/// Closures taking a `&GameEvent` are observers, and so are vectors, which collect every event.
+pub trait GameObserver {
+    fn notify(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> GameObserver for F {
+    fn notify(&mut self, event: &GameEvent) {
+        self(event);
+    }
+}
+
+impl GameObserver for Vec<GameEvent> {
+    fn notify(&mut self, event: &GameEvent) {
+        self.push(event.clone());
+    }
+}
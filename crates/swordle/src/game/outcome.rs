@@ -1,16 +1,31 @@
+use super::GameConfig;
 use crate::guess::Guess;
+use crate::share::{self, ShareStyle};
 use crate::word::Word;
 
 #[derive(Debug, Clone)]
 pub struct GameOutcome {
     solution: Word,
     guesses: Box<[Guess]>,
+    config: GameConfig,
 }
 
 impl GameOutcome {
+    /// Creates the outcome of a game played by the classic rules, with the
+    /// [standard](crate::Dictionary::standard) dictionary.
     #[must_use]
     pub fn new(solution: Word, guesses: Box<[Guess]>) -> Self {
-        GameOutcome { solution, guesses }
+        Self::with_config(solution, guesses, GameConfig::for_solution(solution))
+    }
+
+    /// Creates the outcome of a game played by the rules in a [`GameConfig`].
+    #[must_use]
+    pub fn with_config(solution: Word, guesses: Box<[Guess]>, config: GameConfig) -> Self {
+        GameOutcome {
+            solution,
+            guesses,
+            config,
+        }
     }
 
     #[must_use]
@@ -27,4 +42,19 @@ impl GameOutcome {
     pub fn guesses(&self) -> &[Guess] {
         &self.guesses
     }
+
+    /// Returns the configuration the game was played with.
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Renders the emoji share text for the game, e.g. `Wordle 1,234 4/6` followed by a row of
+    /// squares for each guess.
+    ///
+    /// The puzzle number (see [`Date::puzzle_number`](crate::daily::Date::puzzle_number)) is left
+    /// out if there isn't one. Share grids can be parsed back with [`share::parse`].
+    #[must_use]
+    pub fn share_text(&self, puzzle: Option<i64>, style: ShareStyle) -> String {
+        share::render(self, puzzle, style)
+    }
 }
@@ -1,28 +1,165 @@
-use super::{Game, GameOutcome};
-use crate::{Guess, Word, letter_bank::LetterBank};
-
-const MAX_GUESSES: usize = 6;
+use super::{Game, GameConfig, GameEvent, GameObserver, GameOutcome, MAX_GUESSES, SolutionChoice};
+use crate::letter_bank::LetterBank;
+use crate::word::WordError;
+use crate::{Dictionary, Guess, Knowledge, Word, alphabet, daily::Date};
+use rand::{Rng, SeedableRng as _, rngs::StdRng};
+use std::fmt::Display;
+use std::sync::Arc;
 
 /// A game currently in-progress.
 /// When in this state, the player has not yet guessed the solution.
 #[must_use]
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct PlayingGame {
-    solution: Word,
+    /// Shared so that games stay cheap to move around, e.g. in a [`RejectedGuess`].
+    config: Arc<GameConfig>,
+    pub(crate) solution: Word,
     guesses: Vec<Guess>,
     letter_bank: LetterBank,
+    /// Words taken back by [`undo`](Self::undo), most recent last.
+    undone: Vec<Word>,
 }
 
 impl PlayingGame {
-    /// Creates a new game from a [`Dictionary`].
+    /// Creates a new game using the [standard](Dictionary::standard) dictionary.
     pub fn new() -> Self {
+        Self::with_dictionary(Dictionary::standard())
+    }
+
+    /// Creates a new game from a [`Dictionary`], with a random solution from it.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>) -> Self {
+        Self::with_rng(dictionary, &mut rand::rng())
+    }
+
+    /// Creates a new game from a [`Dictionary`], with a solution chosen from it using a given
+    /// random number generator.
+    ///
+    /// Given a seeded generator, every game created this way has the same solution.
+    pub fn with_rng<R: Rng + ?Sized>(dictionary: Arc<Dictionary>, rng: &mut R) -> Self {
+        let solution = dictionary.random_solution_with(rng);
+        Self::new_unchecked(GameConfig::with_dictionary(dictionary), solution)
+    }
+
+    /// Creates the daily puzzle for a given date, so everyone playing on that date gets the same
+    /// solution from a [`Dictionary`].
+    pub fn daily(dictionary: Arc<Dictionary>, date: Date) -> Self {
+        let config =
+            GameConfig::with_dictionary(dictionary).with_solution(SolutionChoice::Daily(date));
+        let solution = config.dictionary().daily_solution(date);
+        Self::new_unchecked(config, solution)
+    }
+
+    /// Creates a new game with a given solution, using a [`Dictionary`] for guesses.
+    ///
+    /// The solution doesn't need to be in the dictionary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if the solution's length differs from the dictionary's
+    /// word length.
+    pub fn with_solution(dictionary: Arc<Dictionary>, solution: Word) -> Result<Self, WordError> {
+        let config =
+            GameConfig::with_dictionary(dictionary).with_solution(SolutionChoice::Fixed(solution));
+        Self::with_config(config)
+    }
+
+    /// Creates a new game played by the rules in a [`GameConfig`], with its solution chosen the
+    /// way the configuration says.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if the configuration has a [fixed](SolutionChoice::Fixed)
+    /// solution whose length differs from the dictionary's word length.
+    pub fn with_config(config: GameConfig) -> Result<Self, WordError> {
+        let dictionary = config.dictionary();
+        let solution = match config.solution() {
+            SolutionChoice::Random => dictionary.random_solution(),
+            SolutionChoice::Seeded(seed) => {
+                dictionary.random_solution_with(&mut StdRng::seed_from_u64(seed))
+            }
+            SolutionChoice::Daily(date) => dictionary.daily_solution(date),
+            SolutionChoice::Fixed(solution) if solution.len() != dictionary.word_length() => {
+                return Err(WordError::Length(dictionary.word_length()));
+            }
+            SolutionChoice::Fixed(solution) => solution,
+        };
+
+        Ok(Self::new_unchecked(config, solution))
+    }
+
+    /// Creates a new game with a solution already chosen for its configuration, without checking
+    /// that it's the one the configuration would choose.
+    pub(crate) fn new_unchecked(config: GameConfig, solution: Word) -> Self {
         Self {
-            solution: Word::random(),
+            letter_bank: LetterBank::with_alphabet(config.dictionary().alphabet()),
+            config: Arc::new(config),
+            solution,
             guesses: Vec::with_capacity(MAX_GUESSES),
-            letter_bank: LetterBank::new(),
+            undone: Vec::new(),
         }
     }
 
+    /// Returns the configuration the game is played with.
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Turns hard mode on or off.
+    ///
+    /// In hard mode, every guess must use the hints revealed by previous guesses: letters
+    /// revealed as correct must stay in place, and letters revealed as misplaced must be reused.
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.config = Arc::new(Arc::unwrap_or_clone(self.config).with_hard_mode(hard_mode));
+        self
+    }
+
+    /// Returns whether the game is being played in hard mode.
+    #[must_use]
+    pub fn is_hard_mode(&self) -> bool {
+        self.config.is_hard_mode()
+    }
+
+    /// Sets the number of guesses the player has to find the solution (at least 1, and more
+    /// than the guesses already made, since the game is still being played).
+    ///
+    /// Defaults to [`MAX_GUESSES`]. Guesses taken back by [`undo`](Self::undo) that would no
+    /// longer leave a guess to spare can't be [redone](Self::redo).
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        let max_guesses = max_guesses.max(self.guesses.len() + 1);
+        self.config = Arc::new(Arc::unwrap_or_clone(self.config).with_max_guesses(max_guesses));
+
+        // The guesses redone last are the first ones that were taken back.
+        let redoable = max_guesses - 1 - self.guesses.len();
+        let excess = self.undone.len().saturating_sub(redoable);
+        self.undone.drain(..excess);
+        self
+    }
+
+    /// Lets the player make as many guesses as they need to find the solution.
+    pub fn with_unlimited_guesses(mut self) -> Self {
+        self.config = Arc::new(Arc::unwrap_or_clone(self.config).with_unlimited_guesses());
+        self
+    }
+
+    /// Returns the number of guesses the player has to find the solution, or `None` if it's
+    /// unlimited.
+    #[must_use]
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.config.max_guesses()
+    }
+
+    /// Returns the dictionary the game's solution was chosen from.
+    #[must_use]
+    pub fn dictionary(&self) -> &Dictionary {
+        self.config.dictionary()
+    }
+
+    /// Returns the number of letters in the solution (and in every guess).
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.config.word_length()
+    }
+
     /// Returns the guesses the player has made already.
     pub fn guesses(&self) -> &[Guess] {
         &self.guesses
@@ -33,19 +170,194 @@ impl PlayingGame {
         &self.letter_bank
     }
 
+    /// Returns everything the guesses so far reveal about the solution.
+    #[must_use]
+    pub fn knowledge(&self) -> Knowledge {
+        Knowledge::from_guesses(&self.guesses)
+    }
+
     /// Makes a guess using a given [`Word`].
     /// Returns the resulting [`Game`] state.
-    pub fn guess(mut self, word: Word) -> Game {
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RejectedGuess`] containing the unchanged game if the word's length differs
+    /// from the game's [word length](Self::word_length), or if the game is in
+    /// [hard mode](Self::with_hard_mode) and the word doesn't use every revealed hint.
+    pub fn guess(self, word: Word) -> Result<Game, RejectedGuess> {
+        self.guess_observed(word, &mut |_: &GameEvent| {})
+    }
+
+    /// Makes a guess using a given [`Word`] like [`guess`](Self::guess), reporting what happens
+    /// to an [`observer`](GameObserver) as [`GameEvent`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RejectedGuess`] in the same cases as [`guess`](Self::guess), after reporting
+    /// it as [`GameEvent::Rejected`].
+    pub fn guess_observed(
+        mut self,
+        word: Word,
+        observer: &mut (impl GameObserver + ?Sized),
+    ) -> Result<Game, RejectedGuess> {
+        if let Err(error) = self.check(word) {
+            observer.notify(&GameEvent::Rejected {
+                word,
+                error: error.clone(),
+            });
+            return Err(RejectedGuess { game: self, error });
+        }
+
+        self.undone.clear();
+        let guess = self.record(word, observer);
+
+        if guess.is_correct() || self.config.is_out_of_guesses(self.guesses.len()) {
+            observer.notify(&if guess.is_correct() {
+                GameEvent::Won {
+                    guesses: self.guesses.len(),
+                }
+            } else {
+                GameEvent::Lost {
+                    solution: self.solution,
+                }
+            });
+
+            let guesses = self.guesses.into_boxed_slice();
+            let config = Arc::unwrap_or_clone(self.config);
+            let outcome = GameOutcome::with_config(self.solution, guesses, config);
+            return Ok(Game::Over(outcome));
+        }
+
+        Ok(Game::Playing(self))
+    }
+
+    /// Scores a word that's already been checked and adds it to the guesses, reporting the guess
+    /// and the letters it changed to an observer.
+    fn record(&mut self, word: Word, observer: &mut (impl GameObserver + ?Sized)) -> Guess {
         let guess = Guess::new(&self.solution, word);
-        self.letter_bank.report_guess(&guess);
+        observer.notify(&GameEvent::Guessed(guess));
+        self.letter_bank
+            .report_guess_changes(&guess, |letter, previous, placement| {
+                observer.notify(&GameEvent::LetterChanged {
+                    letter: char::from(letter),
+                    previous,
+                    placement,
+                });
+            });
+
         self.guesses.push(guess);
+        guess
+    }
 
-        if self.guesses.last().is_some_and(Guess::is_correct) || self.guesses.len() >= MAX_GUESSES {
-            let outcome = GameOutcome::new(self.solution, self.guesses.into_boxed_slice());
-            return Game::Over(outcome);
+    /// Guesses each of a list of words in turn, e.g. to rebuild a game (and its letter bank) from
+    /// its solution and guesses.
+    ///
+    /// Returns the resulting [`Game`] state, which is over if the last word ended the game.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RejectedGuess`] if any word is rejected, containing the game as it was before
+    /// that word. If the game would end before the last word, nothing is guessed and the error is
+    /// [`GuessError::Over`].
+    pub fn with_guesses(self, words: &[Word]) -> Result<Game, RejectedGuess> {
+        let before_last = words.split_last().map_or(words, |(_, before)| before);
+        if self
+            .config
+            .is_out_of_guesses(self.guesses.len() + before_last.len())
+            || before_last.contains(&self.solution)
+        {
+            return Err(RejectedGuess {
+                game: self,
+                error: GuessError::Over,
+            });
         }
 
-        Game::Playing(self)
+        let mut game = Game::Playing(self);
+        for &word in words {
+            let Game::Playing(playing) = game else {
+                unreachable!("the game can only end on the last word");
+            };
+
+            game = playing.guess(word)?;
+        }
+
+        Ok(game)
+    }
+
+    /// Takes back the latest guess, which can be made again with [`redo`](Self::redo).
+    ///
+    /// Returns the guess that was taken back, or `None` if there are no guesses.
+    pub fn undo(&mut self) -> Option<Guess> {
+        let guess = self.guesses.pop()?;
+        self.undone.push(*guess.word());
+
+        // Letter banks only ever learn more, so they're rebuilt from the guesses that are left.
+        self.letter_bank = LetterBank::with_alphabet(self.letter_bank.alphabet());
+        for guess in &self.guesses {
+            self.letter_bank.report_guess(guess);
+        }
+
+        Some(guess)
+    }
+
+    /// Makes the latest guess taken back by [`undo`](Self::undo) again. This can't end the
+    /// game, since the game was still being played after that guess the first time.
+    ///
+    /// Returns the guess that was made again, or `None` if there's nothing to redo. Making any
+    /// other guess clears the guesses there are to redo.
+    pub fn redo(&mut self) -> Option<Guess> {
+        let word = self.undone.pop()?;
+        Some(self.record(word, &mut |_: &GameEvent| {}))
+    }
+
+    /// Returns whether there are any guesses taken back by [`undo`](Self::undo) to
+    /// [`redo`](Self::redo).
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Checks whether a word would be accepted as the next guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`GuessError`] that [`guess`](Self::guess) would reject the word with.
+    pub fn check(&self, word: Word) -> Result<(), GuessError> {
+        if word.len() != self.word_length() {
+            return Err(GuessError::Length(self.word_length()));
+        }
+
+        if !self.config.is_hard_mode() {
+            return Ok(());
+        }
+
+        let knowledge = self.knowledge();
+        for (position, &guessed) in word.as_bytes().iter().enumerate() {
+            match knowledge.fixed(position) {
+                Some(letter) if letter != guessed => {
+                    return Err(GuessError::Correct {
+                        position,
+                        letter: char::from(letter),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // Letters are checked in the order they were revealed, so the first missing one is named.
+        let revealed = self.guesses.iter().flat_map(|g| g.word().as_bytes().iter());
+        for &letter in revealed {
+            // Every revealed (correct or misplaced) occurrence of the letter must be reused.
+            let required = knowledge.min_count(letter);
+            if word.chars().filter(|&c| c == char::from(letter)).count() < required {
+                return Err(GuessError::Misplaced {
+                    letter: char::from(letter),
+                    count: required,
+                });
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -54,3 +366,441 @@ impl Default for PlayingGame {
         Self::new()
     }
 }
+
+/// A guess that a game (a [`PlayingGame`] unless otherwise specified) refused to accept, along
+/// with the unchanged game.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{error}")]
+pub struct RejectedGuess<G = PlayingGame> {
+    /// The game, exactly as it was before the guess.
+    pub game: G,
+
+    /// Why the guess was rejected.
+    #[source]
+    pub error: GuessError,
+}
+
+/// Errors that may occur when making a guess in a [`PlayingGame`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GuessError {
+    /// Occurs when a guess has a different number of letters than the solution.
+    #[error("Guess must be exactly {0} letters")]
+    Length(usize),
+
+    /// Occurs in hard mode when a guess moves or drops a letter revealed as correct.
+    #[error("{} letter must be {}", Ordinal(position + 1), alphabet::uppercase(*letter))]
+    Correct { position: usize, letter: char },
+
+    /// Occurs in hard mode when a guess doesn't reuse a letter revealed as misplaced (as many
+    /// times as it was revealed).
+    #[error("Guess must contain {}", LetterCount(*letter, *count))]
+    Misplaced { letter: char, count: usize },
+
+    /// Occurs when [replaying](PlayingGame::with_guesses) guesses that continue after the game
+    /// would have ended.
+    #[error("Game is over before every guess is made")]
+    Over,
+}
+
+/// Formats a number as an english ordinal, e.g. `2nd`.
+struct Ordinal(usize);
+
+impl Display for Ordinal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let suffix = match (self.0 % 10, self.0 % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+
+        write!(f, "{}{suffix}", self.0)
+    }
+}
+
+/// Formats a letter and how many times it's required, e.g. `A` or `2 E's`.
+struct LetterCount(char, usize);
+
+impl Display for LetterCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = alphabet::uppercase(self.0);
+        match self.1 {
+            1 => write!(f, "{letter}"),
+            count => write!(f, "{count} {letter}'s"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alphabet, Placement};
+
+    fn new_game(solution: &str, hard_mode: bool) -> PlayingGame {
+        let solution = Word::new(solution).unwrap();
+        PlayingGame::with_solution(Dictionary::standard(), solution)
+            .unwrap()
+            .with_hard_mode(hard_mode)
+    }
+
+    fn guess(game: PlayingGame, word: &str) -> Result<PlayingGame, RejectedGuess> {
+        match game.guess(Word::new(word).unwrap())? {
+            Game::Playing(game) => Ok(game),
+            Game::Over(_) => panic!("game ended after guessing {word}"),
+        }
+    }
+
+    #[test]
+    fn normal_mode_accepts_any_word() {
+        let game = guess(new_game("hunky", false), "salet").unwrap();
+        let game = guess(game, "puppy").unwrap();
+        assert_eq!(game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn hard_mode_rejects_guesses_ignoring_hints() {
+        // crane vs. those: only the final E is correct
+        let game = guess(new_game("those", true), "crane").unwrap();
+        let rejected = guess(game, "salet").unwrap_err();
+        assert_eq!(
+            rejected.error,
+            GuessError::Correct {
+                position: 4,
+                letter: 'e'
+            }
+        );
+        assert_eq!(rejected.to_string(), "5th letter must be E");
+        assert_eq!(rejected.game.guesses().len(), 1);
+
+        // salet vs. lemon: L and E are misplaced
+        let game = guess(new_game("lemon", true), "salet").unwrap();
+        let rejected = guess(game, "crane").unwrap_err();
+        assert_eq!(rejected.to_string(), "Guess must contain L");
+
+        let game = guess(rejected.game, "melon").unwrap();
+        assert_eq!(game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn hard_mode_requires_repeated_letters() {
+        // geese vs. emcee: every E is revealed, and the last is correct
+        let game = guess(new_game("emcee", true), "geese").unwrap();
+        let rejected = guess(game, "there").unwrap_err();
+        assert_eq!(rejected.to_string(), "Guess must contain 3 E's");
+    }
+
+    #[test]
+    fn games_use_the_dictionary_alphabet() {
+        let dictionary = Dictionary::new(["niño", "peña", "caña"], std::iter::empty::<&str>());
+        let dictionary = Arc::new(dictionary.unwrap());
+        let word = |w| Word::with_dictionary(w, &dictionary).unwrap();
+
+        let game = PlayingGame::with_solution(Arc::clone(&dictionary), word("peña")).unwrap();
+        let Ok(Game::Playing(game)) = game.with_hard_mode(true).guess(word("caña")) else {
+            panic!("caña isn't the solution");
+        };
+        assert_eq!(game.letter_bank().alphabet(), Alphabet::SPANISH);
+        assert_eq!(game.letter_bank().get(&b'\xf1'), Some(Placement::Correct));
+
+        let rejected = game.guess(word("niño")).unwrap_err();
+        assert_eq!(rejected.to_string(), "4th letter must be A");
+        assert!(rejected.game.knowledge().matches(&word("peña")));
+    }
+
+    #[test]
+    fn guesses_report_events() {
+        let mut events = Vec::new();
+        let word = |w| Word::new(w).unwrap();
+        let Ok(Game::Playing(game)) =
+            new_game("hunky", true).guess_observed(word("salet"), &mut events)
+        else {
+            panic!("salet isn't the solution");
+        };
+
+        let letter = |letter, previous, placement| GameEvent::LetterChanged {
+            letter,
+            previous,
+            placement,
+        };
+        assert_eq!(events[0], GameEvent::Guessed(game.guesses()[0]));
+        assert_eq!(
+            events[1..],
+            "salet"
+                .chars()
+                .map(|c| letter(c, None, Placement::Incorrect))
+                .collect::<Vec<_>>()
+        );
+
+        // courd vs. hunky: only U is revealed, and it's misplaced
+        events.clear();
+        let Ok(Game::Playing(game)) = game.guess_observed(word("courd"), &mut events) else {
+            panic!("courd isn't the solution");
+        };
+        assert!(events.contains(&letter('u', None, Placement::Misplaced)));
+        assert!(!events.contains(&letter('s', None, Placement::Incorrect)));
+
+        events.clear();
+        let rejected = game.guess_observed(word("pizza"), &mut events).unwrap_err();
+        assert_eq!(
+            events,
+            [GameEvent::Rejected {
+                word: word("pizza"),
+                error: rejected.error.clone()
+            }]
+        );
+
+        let mut won = None;
+        let mut changed = 0;
+        let observer = &mut |event: &GameEvent| match event {
+            GameEvent::Won { guesses } => won = Some(*guesses),
+            GameEvent::LetterChanged { .. } => changed += 1,
+            _ => {}
+        };
+        let Ok(Game::Over(_)) = rejected.game.guess_observed(word("hunky"), observer) else {
+            panic!("hunky is the solution");
+        };
+        assert_eq!(won, Some(3));
+        // The 5 letters in hunky change, and so do the 12 that weren't guessed yet (which are now
+        // known to be incorrect).
+        assert_eq!(changed, 17);
+
+        let mut events = Vec::new();
+        let game = new_game("hunky", false).with_max_guesses(1);
+        let _ = game.guess_observed(word("salet"), &mut events);
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::Lost {
+                solution: word("hunky")
+            })
+        );
+    }
+
+    #[test]
+    fn repeated_letters_are_reported_once() {
+        // eerie vs. those: the first two E's are incorrect and the last is correct, but E only
+        // changes once, straight to its best placement.
+        let mut events = Vec::new();
+        let _ = new_game("those", false).guess_observed(Word::new("eerie").unwrap(), &mut events);
+
+        let changes: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::LetterChanged {
+                    letter,
+                    previous,
+                    placement,
+                } => Some((*letter, *previous, *placement)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ('e', None, Placement::Correct),
+                ('r', None, Placement::Incorrect),
+                ('i', None, Placement::Incorrect),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_guesses_ends_the_game() {
+        let game = new_game("hunky", false).with_max_guesses(2);
+        let game = guess(game, "salet").unwrap();
+        let Ok(Game::Over(outcome)) = game.guess(Word::new("courd").unwrap()) else {
+            panic!("game should be over after 2 guesses");
+        };
+
+        assert!(!outcome.won());
+        assert_eq!(outcome.guesses().len(), 2);
+    }
+
+    #[test]
+    fn max_guesses_leave_a_guess_in_games_being_played() {
+        let game = guess(new_game("hunky", false), "salet").unwrap();
+        let game = guess(game, "courd").unwrap();
+        let mut game = guess(game, "gimpy").unwrap().with_max_guesses(1);
+        assert_eq!(game.max_guesses(), Some(4));
+
+        game.undo();
+        game.undo();
+        let mut game = game.with_max_guesses(3);
+        assert_eq!(game.max_guesses(), Some(3));
+        assert_eq!(game.redo().unwrap().word().as_str(), "courd");
+        assert!(!game.can_redo());
+
+        let Ok(Game::Over(outcome)) = game.guess(Word::new("funky").unwrap()) else {
+            panic!("game should be over after 3 guesses");
+        };
+        assert!(!outcome.won());
+    }
+
+    #[test]
+    fn guesses_must_match_word_length() {
+        let planet = Word::new_no_dict("planet", 6).unwrap();
+        let rejected = new_game("hunky", false).guess(planet).unwrap_err();
+        assert_eq!(rejected.error, GuessError::Length(5));
+    }
+
+    #[test]
+    fn with_solution_checks_length() {
+        let planet = Word::new_no_dict("planet", 6).unwrap();
+        let error = PlayingGame::with_solution(Dictionary::standard(), planet).unwrap_err();
+        assert_eq!(error, WordError::Length(5));
+    }
+
+    #[test]
+    fn seeded_and_daily_games_are_deterministic() {
+        let seeded = || {
+            let mut rng = StdRng::seed_from_u64(7);
+            PlayingGame::with_rng(Dictionary::standard(), &mut rng).solution
+        };
+        assert_eq!(seeded(), seeded());
+
+        let date = Date::new(2025, 1, 1).unwrap();
+        let daily = || PlayingGame::daily(Dictionary::standard(), date).solution;
+        assert_eq!(daily(), daily());
+        assert_eq!(daily(), Dictionary::standard().daily_solution(date));
+    }
+
+    #[test]
+    fn configs_choose_solutions() {
+        let date = Date::new(2025, 1, 1).unwrap();
+        let choose = |choice| {
+            let config = GameConfig::new().with_solution(choice);
+            PlayingGame::with_config(config).unwrap().solution
+        };
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let seeded = Dictionary::standard().random_solution_with(&mut rng);
+        assert_eq!(choose(SolutionChoice::Seeded(7)), seeded);
+        assert_eq!(
+            choose(SolutionChoice::Daily(date)),
+            Dictionary::standard().daily_solution(date)
+        );
+
+        let hunky = Word::new("hunky").unwrap();
+        assert_eq!(choose(SolutionChoice::Fixed(hunky)), hunky);
+
+        let planet = Word::new_no_dict("planet", 6).unwrap();
+        let config = GameConfig::new().with_solution(SolutionChoice::Fixed(planet));
+        let error = PlayingGame::with_config(config).unwrap_err();
+        assert_eq!(error, WordError::Length(5));
+    }
+
+    #[test]
+    fn outcomes_record_the_config() {
+        let mut game = new_game("hunky", true).with_unlimited_guesses();
+        assert_eq!(game.max_guesses(), None);
+        for _ in 0..MAX_GUESSES * 2 {
+            game = guess(game, "salet").unwrap_or_else(|_| panic!("salet is always allowed"));
+        }
+
+        let Ok(Game::Over(outcome)) = game.guess(Word::new("hunky").unwrap()) else {
+            panic!("game should be over once it's won");
+        };
+        assert_eq!(outcome.guesses().len(), MAX_GUESSES * 2 + 1);
+        assert!(outcome.config().is_hard_mode());
+        assert_eq!(outcome.config().max_guesses(), None);
+        assert_eq!(
+            outcome.config().solution(),
+            SolutionChoice::Fixed(*outcome.solution())
+        );
+    }
+
+    #[test]
+    fn knowledge_reflects_guesses() {
+        let game = guess(new_game("hunky", false), "salet").unwrap();
+        let game = guess(game, "courd").unwrap();
+
+        let knowledge = game.knowledge();
+        assert!(knowledge.matches(&game.solution));
+        assert_eq!(knowledge.min_count(b'u'), 1);
+        assert!(knowledge.is_excluded(2, b'u'));
+        assert_eq!(knowledge.max_count(b's'), Some(0));
+    }
+
+    #[test]
+    fn undo_and_redo_work() {
+        let game = guess(new_game("hunky", false), "salet").unwrap();
+        let mut game = guess(game, "courd").unwrap();
+        let before = game.letter_bank().clone();
+        assert!(!game.can_redo());
+
+        let undone = game.undo().unwrap();
+        assert_eq!(undone.word().as_str(), "courd");
+        assert_eq!(game.guesses().len(), 1);
+        assert_eq!(game.letter_bank().get(&b'u'), None);
+        assert!(game.can_redo());
+
+        assert_eq!(game.redo(), Some(undone));
+        assert_eq!(game.guesses().last(), Some(&undone));
+        assert_eq!(game.letter_bank(), &before);
+
+        game.undo();
+        game.undo();
+        assert!(game.undo().is_none());
+        assert!(game.can_redo());
+
+        let game = guess(game, "puppy").unwrap();
+        assert!(!game.can_redo());
+    }
+
+    #[test]
+    fn with_guesses_rebuilds_games() {
+        let words = ["salet", "courd", "gimpy"].map(|w| Word::new(w).unwrap());
+        let mut incremental = new_game("hunky", false);
+        for word in words {
+            incremental = guess(incremental, word.as_str()).unwrap();
+        }
+
+        let Ok(Game::Playing(replayed)) = new_game("hunky", false).with_guesses(&words) else {
+            panic!("replay shouldn't end the game");
+        };
+        assert_eq!(replayed.guesses(), incremental.guesses());
+        assert_eq!(replayed.letter_bank(), incremental.letter_bank());
+
+        let hunky = Word::new("hunky").unwrap();
+        let Ok(Game::Over(outcome)) = new_game("hunky", false).with_guesses(&[words[0], hunky])
+        else {
+            panic!("replay should end with the solution");
+        };
+        assert!(outcome.won());
+    }
+
+    #[test]
+    fn with_guesses_rejects_invalid_replays() {
+        let words = ["salet", "hunky", "courd"].map(|w| Word::new(w).unwrap());
+        let rejected = new_game("hunky", false).with_guesses(&words).unwrap_err();
+        assert_eq!(rejected.error, GuessError::Over);
+        assert!(rejected.game.guesses().is_empty());
+
+        let rejected = new_game("hunky", false)
+            .with_max_guesses(1)
+            .with_guesses(&words[..2])
+            .unwrap_err();
+        assert_eq!(rejected.error, GuessError::Over);
+
+        // melon vs. lemon reveals the E in place, which crane moves
+        let words = ["salet", "melon", "crane"].map(|w| Word::new(w).unwrap());
+        let rejected = new_game("lemon", true).with_guesses(&words).unwrap_err();
+        assert_eq!(rejected.to_string(), "2nd letter must be E");
+        assert_eq!(rejected.game.guesses().len(), 2);
+    }
+
+    #[test]
+    fn ordinals_format_correctly() {
+        let cases = [
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (22, "22nd"),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(Ordinal(n).to_string(), expected);
+        }
+    }
+}
@@ -1,45 +1,68 @@
+use crate::pattern::{Pattern, PatternError};
 use crate::placement::Placement;
-use crate::word::{WORD_LENGTH, Word};
+use crate::word::{MAX_WORD_LENGTH, Word};
 use colored::Colorize as _;
 use std::fmt::Display;
 
-const CORRECT_PLACEMENTS: &[Placement; WORD_LENGTH] = &[Placement::Correct; WORD_LENGTH];
+const CORRECT_PLACEMENTS: &[Placement; MAX_WORD_LENGTH] = &[Placement::Correct; MAX_WORD_LENGTH];
 
 #[must_use]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Guess {
     Correct(Word),
-    Incorrect(Word, [Placement; WORD_LENGTH]),
+
+    /// An incorrect guess, along with the placement of each of its letters.
+    /// Placements past the end of the word are always [`Placement::Incorrect`].
+    Incorrect(Word, [Placement; MAX_WORD_LENGTH]),
 }
 
 impl Guess {
+    /// Scores a guess against a solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guess and solution have different lengths.
     pub fn new(solution: &Word, guess: Word) -> Self {
-        if solution == &guess {
-            return Guess::Correct(guess);
+        let pattern = Pattern::score(solution, &guess);
+        if pattern.is_solved() {
+            Guess::Correct(guess)
+        } else {
+            Guess::Incorrect(guess, pattern.padded())
         }
+    }
 
-        let mut placements = [Placement::Incorrect; WORD_LENGTH];
-        let mut sol_counts = std::collections::HashMap::with_capacity(WORD_LENGTH);
+    /// Creates a guess from a word and the pattern of placements it revealed, written in any
+    /// form [`Pattern`] can parse, e.g. `crane` and `BYGBB`.
+    ///
+    /// This is how feedback from a game played elsewhere is entered, since the solution isn't
+    /// known.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatternError`] if the pattern can't be parsed, or its length differs from the
+    /// word's.
+    pub fn from_pattern(word: Word, pattern: &str) -> Result<Self, PatternError> {
+        Self::with_pattern(word, pattern.parse()?)
+    }
 
-        for (i, placement) in placements.iter_mut().enumerate() {
-            if solution[i] == guess[i] {
-                *placement = Placement::Correct;
-            } else {
-                *sol_counts.entry(solution[i]).or_insert(0) += 1;
-            }
+    /// Creates a guess from a word and the pattern of placements it revealed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PatternError::Length`] if the pattern's length differs from the word's.
+    pub fn with_pattern(word: Word, pattern: Pattern) -> Result<Self, PatternError> {
+        if pattern.len() != word.len() {
+            return Err(PatternError::Length {
+                expected: word.len(),
+                found: pattern.len(),
+            });
         }
 
-        for (i, placement) in placements.iter_mut().enumerate() {
-            if let Some(count) = sol_counts.get_mut(&guess[i])
-                && *count > 0
-                && *placement != Placement::Correct
-            {
-                *placement = Placement::Misplaced;
-                *count -= 1;
-            }
+        if pattern.is_solved() {
+            Ok(Guess::Correct(word))
+        } else {
+            Ok(Guess::Incorrect(word, pattern.padded()))
         }
-
-        Guess::Incorrect(guess, placements)
     }
 
     #[must_use]
@@ -47,28 +70,49 @@ impl Guess {
         matches!(self, Guess::Correct(_))
     }
 
+    /// Returns whether this guess would have the same outcome if the given word were the
+    /// solution, i.e. whether the word could still be the solution.
+    #[must_use]
+    pub fn is_consistent_with(&self, solution: &Word) -> bool {
+        solution.len() == self.word().len()
+            && Pattern::score(solution, self.word()) == self.pattern()
+    }
+
     pub const fn word(&self) -> &Word {
         match self {
             Guess::Correct(w) | Guess::Incorrect(w, _) => w,
         }
     }
 
+    /// Returns the placement of each letter in the guess.
     #[must_use]
     pub const fn placements(&self) -> &[Placement] {
-        match self {
+        let placements = match self {
             Guess::Correct(_) => CORRECT_PLACEMENTS,
             Guess::Incorrect(_, g) => g,
-        }
+        };
+
+        placements.split_at(self.word().len()).0
+    }
+
+    /// Returns the pattern of placements revealed by the guess.
+    pub fn pattern(&self) -> Pattern {
+        let placements = match self {
+            Guess::Correct(_) => *CORRECT_PLACEMENTS,
+            Guess::Incorrect(_, placements) => *placements,
+        };
+
+        Pattern::from_padded(placements, self.word().len())
     }
 }
 
 impl Display for Guess {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.placements()
-            .iter()
-            .enumerate()
-            .map(|(i, placement)| {
-                let letter = self.word().as_str()[i..=i].bold();
+        self.word()
+            .chars()
+            .zip(self.placements())
+            .map(|(letter, placement)| {
+                let letter = letter.to_string().bold();
                 match placement {
                     Placement::Incorrect => letter.white().dimmed(),
                     Placement::Misplaced => letter.yellow(),
@@ -78,3 +122,90 @@ impl Display for Guess {
             .try_for_each(|p| write!(f, "{p}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Placement::{Correct as G, Incorrect as B, Misplaced as Y};
+
+    fn word(s: &str) -> Word {
+        Word::new_no_dict(s, s.len()).unwrap()
+    }
+
+    #[test]
+    fn new_works_for_any_length() {
+        let cases: &[(&str, &str, &[Placement])] = &[
+            ("cats", "tack", &[Y, G, Y, B]),
+            ("crane", "nacre", &[Y, Y, Y, Y, G]),
+            ("planet", "pallet", &[G, Y, Y, B, G, G]),
+            ("journey", "jeering", &[G, Y, B, G, B, Y, B]),
+        ];
+
+        for (solution, guess, expected) in cases {
+            let guess = Guess::new(&word(solution), word(guess));
+            assert_eq!(guess.placements(), *expected, "{guess:?}");
+        }
+
+        let guess = Guess::new(&word("planet"), word("planet"));
+        assert_eq!(guess.placements(), [G; 6]);
+    }
+
+    #[test]
+    fn new_only_marks_as_many_misplaced_letters_as_the_solution_has() {
+        let guess = Guess::new(&word("abbey"), word("babes"));
+        assert_eq!(guess.placements(), [Y, Y, G, G, B]);
+
+        let guess = Guess::new(&word("crane"), word("eerie"));
+        assert_eq!(guess.placements(), [B, B, Y, B, G]);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn new_panics_for_mismatched_lengths() {
+        let _ = Guess::new(&word("crane"), word("planet"));
+    }
+
+    #[test]
+    fn from_pattern_works() {
+        let guess = Guess::from_pattern(word("crane"), "BYgbb").unwrap();
+        assert_eq!(guess, Guess::new(&word("sharp"), word("crane")));
+        assert_eq!(guess.placements(), [B, Y, G, B, B]);
+
+        let guess = Guess::from_pattern(word("crane"), "GGGGG").unwrap();
+        assert_eq!(guess, Guess::Correct(word("crane")));
+
+        assert_eq!(
+            Guess::from_pattern(word("crane"), "BYGB"),
+            Err(PatternError::Length {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            Guess::from_pattern(word("crane"), "BYGBX"),
+            Err(PatternError::Placement('X'))
+        );
+
+        let guess = Guess::from_pattern(word("crane"), "⬛🟨🟩⬛⬛").unwrap();
+        assert_eq!(guess.pattern().to_string(), "BYGBB");
+    }
+
+    #[test]
+    fn is_consistent_with_works() {
+        let guess = Guess::new(&word("planet"), word("pallet"));
+        assert!(guess.is_consistent_with(&word("planet")));
+        assert!(!guess.is_consistent_with(&word("pallet")));
+        assert!(!guess.is_consistent_with(&word("crane")));
+    }
+
+    #[test]
+    fn display_works_for_any_letters() {
+        let nino = Word::new_no_dict("niño", 4).unwrap();
+        let guess = Guess::new(&nino, Word::new_no_dict("ñoño", 4).unwrap());
+        let text = guess.to_string();
+
+        // Only the letters are checked, since whether they're colored depends on the terminal.
+        let letters: String = text.chars().filter(|&c| "ño".contains(c)).collect();
+        assert_eq!(letters, "ñoño");
+    }
+}
@@ -0,0 +1,241 @@
+//! Everything the guesses in a game reveal about its solution.
+
+use crate::word::MAX_WORD_LENGTH;
+use crate::{Guess, Placement, Word};
+
+/// Letters are a byte each, so everything known about them can be kept in tables with an entry
+/// for every byte, whatever the alphabet.
+const LETTERS: usize = 256;
+
+/// What a list of guesses reveals about the solution: the letters fixed in place, the letters
+/// ruled out of each position, and how many times each letter may appear.
+///
+/// A word [matches](Self::matches) the knowledge exactly when it would have given every reported
+/// guess the same pattern, i.e. when it's still a possible solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Knowledge {
+    /// The number of letters in the solution, once a guess has revealed it.
+    length: Option<usize>,
+    fixed: [Option<u8>; MAX_WORD_LENGTH],
+    /// The letters known not to be at each position, as a bit for each letter.
+    excluded: [[u128; 2]; MAX_WORD_LENGTH],
+    min_counts: [u8; LETTERS],
+    max_counts: [u8; LETTERS],
+}
+
+impl Knowledge {
+    /// Creates knowledge that rules nothing out.
+    #[must_use]
+    pub fn new() -> Self {
+        Knowledge {
+            length: None,
+            fixed: [None; MAX_WORD_LENGTH],
+            excluded: [[0; 2]; MAX_WORD_LENGTH],
+            min_counts: [0; LETTERS],
+            #[allow(clippy::cast_possible_truncation)]
+            max_counts: [MAX_WORD_LENGTH as u8; LETTERS],
+        }
+    }
+
+    /// Creates the knowledge revealed by a list of guesses.
+    #[must_use]
+    pub fn from_guesses(guesses: &[Guess]) -> Self {
+        let mut knowledge = Self::new();
+        for guess in guesses {
+            knowledge.report_guess(guess);
+        }
+        knowledge
+    }
+
+    /// Adds what a guess reveals to the knowledge.
+    pub fn report_guess(&mut self, guess: &Guess) {
+        let word = guess.word();
+        self.length = Some(word.len());
+
+        let mut revealed = [0u8; LETTERS];
+        let mut capped = [false; LETTERS];
+        for (position, (&letter, &placement)) in
+            word.as_bytes().iter().zip(guess.placements()).enumerate()
+        {
+            let index = usize::from(letter);
+            match placement {
+                Placement::Correct => self.fixed[position] = Some(letter),
+                Placement::Misplaced => exclude(&mut self.excluded[position], letter),
+                Placement::Incorrect => {
+                    // The solution has no more of this letter than the guess revealed.
+                    exclude(&mut self.excluded[position], letter);
+                    capped[index] = true;
+                }
+            }
+
+            if placement != Placement::Incorrect {
+                revealed[index] += 1;
+            }
+        }
+
+        for index in 0..LETTERS {
+            self.min_counts[index] = self.min_counts[index].max(revealed[index]);
+            if capped[index] {
+                self.max_counts[index] = self.max_counts[index].min(revealed[index]);
+            }
+        }
+    }
+
+    /// Returns the number of letters in the solution, or `None` if no guesses have been
+    /// reported.
+    #[must_use]
+    pub fn word_length(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Returns the letter known to be at a position, if there is one.
+    #[must_use]
+    pub fn fixed(&self, position: usize) -> Option<u8> {
+        self.fixed.get(position).copied().flatten()
+    }
+
+    /// Returns whether a letter is known not to be at a position.
+    #[must_use]
+    pub fn is_excluded(&self, position: usize, letter: u8) -> bool {
+        self.excluded.get(position).is_some_and(|excluded| {
+            excluded[usize::from(letter / 128)] & (1 << (letter % 128)) != 0
+        })
+    }
+
+    /// Returns the fewest times a letter may appear in the solution.
+    #[must_use]
+    pub fn min_count(&self, letter: u8) -> usize {
+        usize::from(self.min_counts[usize::from(letter)])
+    }
+
+    /// Returns the most times a letter may appear in the solution, or `None` if no guess has
+    /// capped it.
+    #[must_use]
+    pub fn max_count(&self, letter: u8) -> Option<usize> {
+        let max = self.max_counts[usize::from(letter)];
+        (usize::from(max) < MAX_WORD_LENGTH).then_some(usize::from(max))
+    }
+
+    /// Returns whether a word agrees with everything that's known, i.e. whether it may be the
+    /// solution.
+    #[must_use]
+    pub fn matches(&self, word: &Word) -> bool {
+        if self.length.is_some_and(|length| length != word.len()) {
+            return false;
+        }
+
+        let mut counts = [0u8; LETTERS];
+        for (position, &letter) in word.as_bytes().iter().enumerate() {
+            if self.fixed[position].is_some_and(|fixed| fixed != letter)
+                || self.is_excluded(position, letter)
+            {
+                return false;
+            }
+            counts[usize::from(letter)] += 1;
+        }
+
+        counts
+            .iter()
+            .zip(self.min_counts.iter().zip(&self.max_counts))
+            .all(|(count, (min, max))| (min..=max).contains(&count))
+    }
+}
+
+impl Default for Knowledge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn exclude(excluded: &mut [u128; 2], letter: u8) {
+    excluded[usize::from(letter / 128)] |= 1 << (letter % 128);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dictionary;
+
+    fn word(s: &str) -> Word {
+        Word::new(s).unwrap()
+    }
+
+    fn knowledge(solution: &str, guesses: &[&str]) -> Knowledge {
+        let solution = word(solution);
+        let guesses: Vec<Guess> = guesses
+            .iter()
+            .map(|g| Guess::new(&solution, word(g)))
+            .collect();
+        Knowledge::from_guesses(&guesses)
+    }
+
+    #[test]
+    fn positions_are_tracked() {
+        // crane vs. those: only the final E is correct
+        let knowledge = knowledge("those", &["crane"]);
+        assert_eq!(knowledge.word_length(), Some(5));
+        assert_eq!(knowledge.fixed(4), Some(b'e'));
+        assert_eq!(knowledge.fixed(0), None);
+        assert!(knowledge.is_excluded(0, b'c'));
+        assert!(!knowledge.is_excluded(1, b'c'));
+
+        // salet vs. those: S and E are misplaced
+        let knowledge = self::knowledge("those", &["salet"]);
+        assert!(knowledge.is_excluded(0, b's'));
+        assert!(knowledge.is_excluded(3, b'e'));
+        assert_eq!(knowledge.min_count(b's'), 1);
+        assert_eq!(knowledge.max_count(b's'), None);
+    }
+
+    #[test]
+    fn duplicate_letters_are_counted() {
+        // geese vs. emcee: every E is revealed, so there are at least three
+        let knowledge = knowledge("emcee", &["geese"]);
+        assert_eq!(knowledge.min_count(b'e'), 3);
+        assert_eq!(knowledge.max_count(b'e'), None);
+
+        // eerie vs. lemon: only one E is revealed, so there's exactly one
+        let knowledge = self::knowledge("lemon", &["eerie"]);
+        assert_eq!(knowledge.min_count(b'e'), 1);
+        assert_eq!(knowledge.max_count(b'e'), Some(1));
+        assert_eq!(knowledge.max_count(b'r'), Some(0));
+        assert!(knowledge.matches(&word("lemon")));
+        assert!(!knowledge.matches(&word("melee")));
+    }
+
+    #[test]
+    fn empty_knowledge_matches_everything() {
+        let knowledge = Knowledge::new();
+        assert!(knowledge.matches(&word("crane")));
+        assert!(knowledge.matches(&Word::new_no_dict("planet", 6).unwrap()));
+        assert_eq!(knowledge.fixed(0), None);
+        assert_eq!(knowledge.min_count(b'e'), 0);
+    }
+
+    #[test]
+    fn matches_agrees_with_consistent_guesses() {
+        let dictionary = Dictionary::standard();
+        for (solution, guesses) in [
+            ("hunky", &["salet", "courd"][..]),
+            ("emcee", &["geese"]),
+            ("lemon", &["eerie", "melon"]),
+            ("mummy", &["tummy", "mamma"]),
+        ] {
+            let solution = word(solution);
+            let guesses: Vec<Guess> = guesses
+                .iter()
+                .map(|g| Guess::new(&solution, word(g)))
+                .collect();
+
+            let knowledge = Knowledge::from_guesses(&guesses);
+            assert!(knowledge.matches(&solution));
+            for candidate in dictionary.words() {
+                assert_eq!(
+                    knowledge.matches(&candidate),
+                    guesses.iter().all(|g| g.is_consistent_with(&candidate)),
+                    "{candidate} after {guesses:?}"
+                );
+            }
+        }
+    }
+}
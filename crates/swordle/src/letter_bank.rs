@@ -1,49 +1,96 @@
-use crate::{Guess, Placement};
+use crate::{Alphabet, Guess, Placement};
 use colored::Colorize as _;
 use std::fmt::Display;
 
-const BANK_LENGTH: usize = 26;
-
 /// A bank of letters that may be in the solution
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LetterBank([Option<Placement>; BANK_LENGTH]);
+pub struct LetterBank {
+    alphabet: &'static Alphabet,
+    /// The best placement revealed for each letter, in the alphabet's order.
+    placements: Box<[Option<Placement>]>,
+}
 
 impl LetterBank {
+    /// Creates an empty bank of the [English](Alphabet::ENGLISH) letters.
     #[must_use]
     pub fn new() -> Self {
-        LetterBank([None; BANK_LENGTH])
+        Self::with_alphabet(Alphabet::ENGLISH)
+    }
+
+    /// Creates an empty bank of the letters in an [`Alphabet`].
+    #[must_use]
+    pub fn with_alphabet(alphabet: &'static Alphabet) -> Self {
+        LetterBank {
+            alphabet,
+            placements: vec![None; alphabet.num_letters()].into_boxed_slice(),
+        }
+    }
+
+    #[must_use]
+    pub fn alphabet(&self) -> &'static Alphabet {
+        self.alphabet
     }
 
+    /// Returns the best placement revealed for a letter, or `None` if it hasn't been guessed
+    /// (or isn't in the bank's alphabet).
     #[must_use]
     pub fn get(&self, letter: &u8) -> Option<Placement> {
-        self.0[byte_to_index(*letter)]
+        self.alphabet
+            .index_of(*letter)
+            .and_then(|index| self.placements[index])
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn set(&mut self, letter: u8, placement: Placement) {
+        if let Some(index) = self.alphabet.index_of(letter) {
+            self.placements[index] = Some(placement);
+        }
     }
 
+    /// Records the placements revealed by a guess. Letters outside the bank's alphabet are
+    /// ignored.
     pub fn report_guess(&mut self, guess: &Guess) {
-        match guess {
-            Guess::Correct(word) => {
-                word.as_bytes()
-                    .iter()
-                    .copied()
-                    .map(byte_to_index)
-                    .for_each(|i| self.0[i] = Some(Placement::Correct));
-
-                self.0
-                    .iter_mut()
-                    .filter(|v| v.is_none())
-                    .for_each(|v| *v = Some(Placement::Incorrect));
+        self.report_guess_changes(guess, |_, _, _| {});
+    }
+
+    /// Records the placements revealed by a guess, calling `changed` once for each letter whose
+    /// placement changed, with its previous placement and its new one.
+    ///
+    /// A letter guessed more than once only changes to its best placement in the guess, so e.g.
+    /// guessing `eerie` against `those` changes E straight to correct.
+    pub(crate) fn report_guess_changes(
+        &mut self,
+        guess: &Guess,
+        mut changed: impl FnMut(u8, Option<Placement>, Placement),
+    ) {
+        // The best placement of each letter in the guess, in the order they were guessed.
+        let mut revealed: Vec<(usize, u8, Placement)> = Vec::new();
+        let letters = guess.word().as_bytes().iter().zip(guess.placements());
+        for (&letter, &placement) in letters {
+            let Some(index) = self.alphabet.index_of(letter) else {
+                continue;
+            };
+
+            match revealed.iter_mut().find(|(i, _, _)| *i == index) {
+                Some((_, _, best)) => *best = (*best).max(placement),
+                None => revealed.push((index, letter, placement)),
             }
-            Guess::Incorrect(word, placements) => {
-                word.as_bytes()
-                    .iter()
-                    .copied()
-                    .map(byte_to_index)
-                    .zip(placements.iter().copied())
-                    .for_each(|(index, placement)| {
-                        self.0[index] = self.0[index]
-                            .map(|existing| existing.max(placement))
-                            .or(Some(placement));
-                    });
+        }
+
+        // Guessing the solution reveals that every other letter isn't in it.
+        if guess.is_correct() {
+            for (index, letter) in self.alphabet.letters().enumerate() {
+                if !revealed.iter().any(|&(i, _, _)| i == index) {
+                    revealed.push((index, letter, Placement::Incorrect));
+                }
+            }
+        }
+
+        for (index, letter, placement) in revealed {
+            let previous = self.placements[index];
+            if previous < Some(placement) {
+                self.placements[index] = Some(placement);
+                changed(letter, previous, placement);
             }
         }
     }
@@ -57,15 +104,8 @@ impl Default for LetterBank {
 
 impl Display for LetterBank {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let letters = self
-            .0
-            .iter()
-            .enumerate()
-            .map(|(i, p)| (index_to_byte(i), p));
-
-        for (letter, placement) in letters {
-            let letter_arr = [letter];
-            let letter = String::from_utf8_lossy(&letter_arr);
+        for (letter, placement) in self.alphabet.letters().zip(&self.placements) {
+            let letter = char::from(letter).to_string();
             write!(
                 f,
                 "{}",
@@ -82,26 +122,9 @@ impl Display for LetterBank {
     }
 }
 
-fn byte_to_index(byte: u8) -> usize {
-    if byte.is_ascii_lowercase() {
-        (byte - b'a') as usize
-    } else {
-        panic!("called byte_to_index with a non-letter");
-    }
-}
-
-#[allow(clippy::cast_possible_truncation)]
-fn index_to_byte(index: usize) -> u8 {
-    if index < BANK_LENGTH {
-        index as u8 + b'a'
-    } else {
-        panic!("called index_to_byte with out-of-range index");
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::Word;
+    use crate::{Dictionary, Word};
 
     use super::*;
 
@@ -124,6 +147,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn letters_outside_the_alphabet_are_unknown() {
+        let dictionary = Dictionary::new(["niño", "peña"], std::iter::empty::<&str>()).unwrap();
+        let mut letter_bank = LetterBank::with_alphabet(dictionary.alphabet());
+        let solution = Word::with_dictionary("peña", &dictionary).unwrap();
+        let guess = Word::with_dictionary("niño", &dictionary).unwrap();
+
+        letter_bank.report_guess(&Guess::new(&solution, guess));
+
+        assert_eq!(letter_bank.get(&b'\xf1'), Some(Placement::Correct));
+        assert_eq!(letter_bank.get(&b'n'), Some(Placement::Incorrect));
+        assert_eq!(letter_bank.get(&b'\xfc'), None);
+    }
+
     #[test]
     fn report_guess_works_for_incorrect_guesses() {
         let mut letter_bank = LetterBank::new();
@@ -1,14 +1,31 @@
 //! `swordle` is an implementation of the game logic for the classic NYT Wordle game.
 
+pub mod absurdle;
+pub mod alphabet;
+pub mod daily;
+pub mod dictionary;
 pub mod game;
 pub mod guess;
+pub mod knowledge;
 pub mod letter_bank;
+pub mod multi;
+pub mod pattern;
 pub mod placement;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod share;
+pub mod stats;
 pub mod word;
 
+pub use absurdle::Absurdle;
+pub use alphabet::Alphabet;
+pub use dictionary::Dictionary;
 pub use game::Game;
 pub use game::GameOutcome;
 pub use game::PlayingGame;
 pub use guess::Guess;
+pub use knowledge::Knowledge;
+pub use multi::MultiGame;
+pub use pattern::Pattern;
 pub use placement::Placement;
 pub use word::Word;
@@ -0,0 +1,389 @@
+//! Games with several boards played at once, like Dordle (2), Quordle (4) and Octordle (8).
+//!
+//! Every guess is made on every board that hasn't been solved yet, and the game is won once every
+//! board is solved.
+
+use crate::game::{
+    GameConfig, GameOutcome, GuessError, MAX_GUESSES, RejectedGuess, SolutionChoice,
+};
+use crate::word::WordError;
+use crate::{Alphabet, Dictionary, Guess, Word, letter_bank::LetterBank};
+use rand::Rng;
+use rand::seq::IndexedRandom as _;
+use std::sync::Arc;
+
+/// Returns the number of guesses allowed by default for a number of boards: one more than
+/// [`MAX_GUESSES`] for each board after the first, e.g. 9 for Quordle.
+#[must_use]
+pub fn default_max_guesses(boards: usize) -> usize {
+    MAX_GUESSES + boards.saturating_sub(1)
+}
+
+/// The states a multi-board game may be in.
+#[must_use]
+#[derive(Debug, Clone)]
+pub enum MultiGame {
+    Playing(PlayingMultiGame),
+    Over(MultiGameOutcome),
+}
+
+impl MultiGame {
+    /// Creates a new game with a number of boards (at least 1), using the
+    /// [standard](Dictionary::standard) dictionary.
+    pub fn new(boards: usize) -> Self {
+        Self::Playing(PlayingMultiGame::new(boards))
+    }
+
+    /// Creates a new game with a number of boards (at least 1), with different random solutions
+    /// from a [`Dictionary`] where possible.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>, boards: usize) -> Self {
+        Self::Playing(PlayingMultiGame::with_dictionary(dictionary, boards))
+    }
+
+    /// Creates a new game with a board for each given solution, using a [`Dictionary`] for
+    /// guesses.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if any solution's length differs from the dictionary's word
+    /// length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no solutions.
+    pub fn with_solutions(
+        dictionary: Arc<Dictionary>,
+        solutions: &[Word],
+    ) -> Result<Self, WordError> {
+        PlayingMultiGame::with_solutions(dictionary, solutions).map(Self::Playing)
+    }
+}
+
+/// A multi-board game currently in progress, where at least one board hasn't been solved.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct PlayingMultiGame {
+    dictionary: Arc<Dictionary>,
+    boards: Vec<Board>,
+    guesses: Vec<Word>,
+    max_guesses: usize,
+}
+
+impl PlayingMultiGame {
+    /// Creates a new game with a number of boards (at least 1), using the
+    /// [standard](Dictionary::standard) dictionary.
+    pub fn new(boards: usize) -> Self {
+        Self::with_dictionary(Dictionary::standard(), boards)
+    }
+
+    /// Creates a new game with a number of boards (at least 1), with different random solutions
+    /// from a [`Dictionary`] where possible.
+    pub fn with_dictionary(dictionary: Arc<Dictionary>, boards: usize) -> Self {
+        Self::with_rng(dictionary, boards, &mut rand::rng())
+    }
+
+    /// Creates a new game with a number of boards (at least 1), with solutions chosen from a
+    /// [`Dictionary`] using a given random number generator.
+    ///
+    /// Solutions are only repeated if the dictionary has fewer solutions than there are boards.
+    pub fn with_rng<R: Rng + ?Sized>(
+        dictionary: Arc<Dictionary>,
+        boards: usize,
+        rng: &mut R,
+    ) -> Self {
+        let boards = boards.max(1);
+        let mut solutions: Vec<Word> = dictionary
+            .solutions()
+            .choose_multiple(rng, boards)
+            .copied()
+            .collect();
+
+        while solutions.len() < boards {
+            solutions.push(dictionary.random_solution_with(rng));
+        }
+
+        Self::new_unchecked(dictionary, &solutions)
+    }
+
+    /// Creates a new game with a board for each given solution, using a [`Dictionary`] for
+    /// guesses.
+    ///
+    /// The solutions don't need to be in the dictionary.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WordError::Length`] if any solution's length differs from the dictionary's word
+    /// length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no solutions.
+    pub fn with_solutions(
+        dictionary: Arc<Dictionary>,
+        solutions: &[Word],
+    ) -> Result<Self, WordError> {
+        assert!(!solutions.is_empty(), "a game needs at least one board");
+        if solutions
+            .iter()
+            .any(|s| s.len() != dictionary.word_length())
+        {
+            return Err(WordError::Length(dictionary.word_length()));
+        }
+
+        Ok(Self::new_unchecked(dictionary, solutions))
+    }
+
+    fn new_unchecked(dictionary: Arc<Dictionary>, solutions: &[Word]) -> Self {
+        let max_guesses = default_max_guesses(solutions.len());
+        Self {
+            boards: solutions
+                .iter()
+                .map(|&solution| Board::new(solution, dictionary.alphabet()))
+                .collect(),
+            dictionary,
+            guesses: Vec::with_capacity(max_guesses),
+            max_guesses,
+        }
+    }
+
+    /// Sets the number of guesses the player has to solve every board (at least 1).
+    ///
+    /// Defaults to [`default_max_guesses`] for the number of boards.
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses.max(1);
+        self
+    }
+
+    /// Returns the number of guesses the player has to solve every board.
+    #[must_use]
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    /// Returns the dictionary the game's solutions were chosen from.
+    #[must_use]
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Returns the number of letters in each solution (and in every guess).
+    #[must_use]
+    pub fn word_length(&self) -> usize {
+        self.dictionary.word_length()
+    }
+
+    /// Returns every board, in order.
+    #[must_use]
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// Returns the number of boards that have been solved.
+    #[must_use]
+    pub fn num_solved(&self) -> usize {
+        self.boards.iter().filter(|b| b.is_solved()).count()
+    }
+
+    /// Returns the words the player has guessed already.
+    pub fn guesses(&self) -> &[Word] {
+        &self.guesses
+    }
+
+    /// Makes a guess on every board that hasn't been solved yet.
+    /// Returns the resulting [`MultiGame`] state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RejectedGuess`] containing the unchanged game if the word's length
+    /// differs from the game's [word length](Self::word_length).
+    pub fn guess(mut self, word: Word) -> Result<MultiGame, RejectedGuess<Self>> {
+        if word.len() != self.word_length() {
+            let error = GuessError::Length(self.word_length());
+            return Err(RejectedGuess { game: self, error });
+        }
+
+        for board in self.boards.iter_mut().filter(|b| !b.is_solved()) {
+            board.guess(word);
+        }
+        self.guesses.push(word);
+
+        if self.boards.iter().all(Board::is_solved) || self.guesses.len() >= self.max_guesses {
+            let config =
+                GameConfig::with_dictionary(self.dictionary).with_max_guesses(self.max_guesses);
+            let boards = self
+                .boards
+                .into_iter()
+                .map(|b| {
+                    let config = config
+                        .clone()
+                        .with_solution(SolutionChoice::Fixed(b.solution));
+                    GameOutcome::with_config(b.solution, b.guesses.into_boxed_slice(), config)
+                })
+                .collect();
+
+            return Ok(MultiGame::Over(MultiGameOutcome {
+                boards,
+                guesses: self.guesses.into_boxed_slice(),
+            }));
+        }
+
+        Ok(MultiGame::Playing(self))
+    }
+}
+
+/// One board of a [`PlayingMultiGame`], with its own solution, guesses and letter bank.
+#[derive(Debug, Clone)]
+pub struct Board {
+    solution: Word,
+    guesses: Vec<Guess>,
+    letter_bank: LetterBank,
+}
+
+impl Board {
+    fn new(solution: Word, alphabet: &'static Alphabet) -> Self {
+        Board {
+            solution,
+            guesses: Vec::with_capacity(MAX_GUESSES),
+            letter_bank: LetterBank::with_alphabet(alphabet),
+        }
+    }
+
+    fn guess(&mut self, word: Word) {
+        let guess = Guess::new(&self.solution, word);
+        self.letter_bank.report_guess(&guess);
+        self.guesses.push(guess);
+    }
+
+    /// Returns whether the board's solution has been guessed.
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.guesses.last().is_some_and(Guess::is_correct)
+    }
+
+    /// Returns the guesses made on this board, which stop once it's solved.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    #[must_use]
+    pub fn letter_bank(&self) -> &LetterBank {
+        &self.letter_bank
+    }
+}
+
+/// The result of a finished multi-board game.
+#[derive(Debug, Clone)]
+pub struct MultiGameOutcome {
+    boards: Box<[GameOutcome]>,
+    guesses: Box<[Word]>,
+}
+
+impl MultiGameOutcome {
+    /// Returns whether every board was solved.
+    #[must_use]
+    pub fn won(&self) -> bool {
+        self.boards.iter().all(GameOutcome::won)
+    }
+
+    /// Returns the outcome of each board, in order.
+    #[must_use]
+    pub fn boards(&self) -> &[GameOutcome] {
+        &self.boards
+    }
+
+    /// Returns the number of boards that were solved.
+    #[must_use]
+    pub fn num_solved(&self) -> usize {
+        self.boards.iter().filter(|b| b.won()).count()
+    }
+
+    /// Returns every word the player guessed.
+    pub fn guesses(&self) -> &[Word] {
+        &self.guesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng as _, rngs::StdRng};
+
+    fn word(s: &str) -> Word {
+        Word::new(s).unwrap()
+    }
+
+    #[test]
+    fn boards_stop_once_solved() {
+        let solutions = ["hunky", "crane"].map(word);
+        let game = PlayingMultiGame::with_solutions(Dictionary::standard(), &solutions).unwrap();
+
+        let MultiGame::Playing(game) = game.guess(word("crane")).unwrap() else {
+            panic!("game ended before hunky was solved");
+        };
+        assert_eq!(game.num_solved(), 1);
+        assert_eq!(game.guesses(), [word("crane")]);
+
+        let MultiGame::Playing(game) = game.guess(word("salet")).unwrap() else {
+            panic!("game ended before hunky was solved");
+        };
+        let [hunky, crane] = game.boards() else {
+            panic!("game should have 2 boards");
+        };
+        assert_eq!(hunky.guesses().len(), 2);
+        assert_eq!(crane.guesses().len(), 1);
+        assert!(crane.is_solved() && !hunky.is_solved());
+
+        let MultiGame::Over(outcome) = game.guess(word("hunky")).unwrap() else {
+            panic!("game should end once every board is solved");
+        };
+        assert!(outcome.won());
+        assert_eq!(outcome.guesses().len(), 3);
+        assert_eq!(outcome.boards()[0].solution(), &word("hunky"));
+        assert_eq!(outcome.boards()[0].guesses().len(), 3);
+        assert_eq!(outcome.boards()[1].guesses().len(), 1);
+    }
+
+    #[test]
+    fn game_ends_after_max_guesses() {
+        let solutions = ["hunky", "crane", "sharp", "vivid"].map(word);
+        let game = PlayingMultiGame::with_solutions(Dictionary::standard(), &solutions).unwrap();
+        assert_eq!(game.max_guesses(), 9);
+
+        let mut game = MultiGame::Playing(game.with_max_guesses(2));
+        for guess in ["crane", "salet"] {
+            let MultiGame::Playing(playing) = game else {
+                panic!("game ended early");
+            };
+            game = playing.guess(word(guess)).unwrap();
+        }
+
+        let MultiGame::Over(outcome) = game else {
+            panic!("game should end after 2 guesses");
+        };
+        assert!(!outcome.won());
+        assert_eq!(outcome.num_solved(), 1);
+        assert_eq!(outcome.boards()[0].config().max_guesses(), Some(2));
+    }
+
+    #[test]
+    fn guesses_must_have_the_right_length() {
+        let solutions = ["hunky", "crane"].map(word);
+        let rejected = PlayingMultiGame::with_solutions(Dictionary::standard(), &solutions)
+            .unwrap()
+            .guess(Word::new_no_dict("planet", 6).unwrap())
+            .unwrap_err();
+        assert_eq!(rejected.error, GuessError::Length(5));
+        assert!(rejected.game.guesses().is_empty());
+    }
+
+    #[test]
+    fn random_solutions_are_different() {
+        let game =
+            PlayingMultiGame::with_rng(Dictionary::standard(), 8, &mut StdRng::seed_from_u64(8));
+        let mut solutions: Vec<Word> = game.boards().iter().map(|b| b.solution).collect();
+        solutions.sort_unstable();
+        solutions.dedup();
+        assert_eq!(solutions.len(), 8);
+        assert_eq!(game.max_guesses(), 13);
+    }
+}
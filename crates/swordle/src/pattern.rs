@@ -0,0 +1,316 @@
+use crate::share::ShareStyle;
+use crate::word::MAX_WORD_LENGTH;
+use crate::{Placement, Word};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The placements revealed by a guess, one per letter.
+///
+/// Patterns are written one placement per letter, in any of three forms (which may be mixed when
+/// parsing):
+///
+/// - letters: `B`lack, `Y`ellow and `G`reen, e.g. `BYGBB` (this is how patterns are displayed)
+/// - digits: `0`, `1` and `2`, e.g. `01200`
+/// - emoji squares in any [`ShareStyle`], e.g. `⬛🟨🟩⬛⬛`
+///
+/// They can also be [encoded](Pattern::encode) compactly as a base-3 number.
+#[must_use]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pattern {
+    /// Placements past `len` are always [`Placement::Incorrect`].
+    placements: [Placement; MAX_WORD_LENGTH],
+    len: u8,
+}
+
+impl Pattern {
+    /// Creates a pattern from a slice of placements.
+    ///
+    /// Returns `None` if there are no placements, or more than [`MAX_WORD_LENGTH`].
+    #[must_use]
+    pub fn new(placements: &[Placement]) -> Option<Self> {
+        if placements.is_empty() || placements.len() > MAX_WORD_LENGTH {
+            return None;
+        }
+
+        let mut padded = [Placement::Incorrect; MAX_WORD_LENGTH];
+        padded[..placements.len()].copy_from_slice(placements);
+
+        #[allow(clippy::cast_possible_truncation)]
+        Some(Pattern {
+            placements: padded,
+            len: placements.len() as u8,
+        })
+    }
+
+    /// Scores a guess against a solution, returning the pattern of placements it reveals.
+    ///
+    /// This doesn't allocate, so it's suitable for scoring every guess against every solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guess and solution have different lengths.
+    pub fn score(solution: &Word, guess: &Word) -> Self {
+        assert_eq!(
+            solution.len(),
+            guess.len(),
+            "guess and solution must have the same length"
+        );
+
+        let mut placements = [Placement::Incorrect; MAX_WORD_LENGTH];
+        let (solution, guess) = (solution.as_bytes(), guess.as_bytes());
+
+        // Letters are a byte each, so unmatched solution letters can be counted in a fixed-size
+        // array rather than a map.
+        let mut sol_counts = [0u8; 256];
+
+        let letters = solution.iter().zip(guess);
+        for (placement, (&s, &g)) in placements.iter_mut().zip(letters.clone()) {
+            if s == g {
+                *placement = Placement::Correct;
+            } else {
+                sol_counts[usize::from(s)] += 1;
+            }
+        }
+
+        for (placement, (_, &g)) in placements.iter_mut().zip(letters) {
+            let count = &mut sol_counts[usize::from(g)];
+            if *count > 0 && *placement != Placement::Correct {
+                *placement = Placement::Misplaced;
+                *count -= 1;
+            }
+        }
+
+        Self::from_padded(placements, guess.len())
+    }
+
+    /// Creates a pattern from the first `len` of an array of placements, which must be between 1
+    /// and [`MAX_WORD_LENGTH`].
+    pub(crate) fn from_padded(mut placements: [Placement; MAX_WORD_LENGTH], len: usize) -> Self {
+        placements[len..].fill(Placement::Incorrect);
+
+        #[allow(clippy::cast_possible_truncation)]
+        Pattern {
+            placements,
+            len: len as u8,
+        }
+    }
+
+    /// Creates the pattern revealed by guessing the solution, with every placement correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `length` is 0 or greater than [`MAX_WORD_LENGTH`].
+    pub fn solved(length: usize) -> Self {
+        Self::new(&[Placement::Correct; MAX_WORD_LENGTH][..length])
+            .expect("length must be between 1 and MAX_WORD_LENGTH")
+    }
+
+    /// Returns the placement of each letter.
+    #[must_use]
+    pub fn placements(&self) -> &[Placement] {
+        &self.placements[..self.len()]
+    }
+
+    /// Returns the placements padded to [`MAX_WORD_LENGTH`] with [`Placement::Incorrect`].
+    #[must_use]
+    pub fn padded(&self) -> [Placement; MAX_WORD_LENGTH] {
+        self.placements
+    }
+
+    /// Returns the number of placements in the pattern.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Always returns `false`, since patterns have at least one placement.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether every placement is correct, i.e. the guess was the solution.
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.placements().iter().all(|&p| p == Placement::Correct)
+    }
+
+    /// Returns the number of distinct patterns of a given length, i.e. `3^length`.
+    #[must_use]
+    pub fn count(length: usize) -> usize {
+        3usize.pow(u32::try_from(length).unwrap_or(u32::MAX))
+    }
+
+    /// Encodes the pattern as a base-3 number below [`Pattern::count`], where each placement is
+    /// a digit (`Incorrect` = 0, `Misplaced` = 1, `Correct` = 2) and the first is the most
+    /// significant, e.g. `BYGBB` is `0*81 + 1*27 + 2*9 + 0*3 + 0 = 45`.
+    ///
+    /// Classic 5-letter patterns encode to `0..243`.
+    #[must_use]
+    pub fn encode(&self) -> u16 {
+        self.placements()
+            .iter()
+            .fold(0, |code, &placement| code * 3 + u16::from(digit(placement)))
+    }
+
+    /// Decodes a pattern of a given length from its [encoding](Pattern::encode).
+    ///
+    /// Returns `None` if the length is invalid or the code is too large for it.
+    #[must_use]
+    pub fn decode(mut code: u16, length: usize) -> Option<Self> {
+        if length == 0 || length > MAX_WORD_LENGTH || usize::from(code) >= Self::count(length) {
+            return None;
+        }
+
+        let mut placements = [Placement::Incorrect; MAX_WORD_LENGTH];
+        for placement in placements[..length].iter_mut().rev() {
+            *placement = match code % 3 {
+                0 => Placement::Incorrect,
+                1 => Placement::Misplaced,
+                _ => Placement::Correct,
+            };
+            code /= 3;
+        }
+
+        Self::new(&placements[..length])
+    }
+
+    /// Formats the pattern as digits, e.g. `01200`.
+    #[must_use]
+    pub fn to_digits(&self) -> String {
+        self.placements()
+            .iter()
+            .map(|&p| char::from(b'0' + digit(p)))
+            .collect()
+    }
+
+    /// Formats the pattern as emoji squares, e.g. `⬛🟨🟩⬛⬛`.
+    #[must_use]
+    pub fn to_emoji(&self, style: ShareStyle) -> String {
+        self.placements().iter().map(|&p| style.square(p)).collect()
+    }
+}
+
+fn digit(placement: Placement) -> u8 {
+    match placement {
+        Placement::Incorrect => 0,
+        Placement::Misplaced => 1,
+        Placement::Correct => 2,
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.placements().iter().try_for_each(|p| write!(f, "{p}"))
+    }
+}
+
+impl std::fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Pattern").field(&self.to_string()).finish()
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    /// Parses a pattern written as letters, digits or emoji, ignoring surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut placements = Vec::with_capacity(MAX_WORD_LENGTH);
+        // Emoji squares may be followed by an invisible variation selector.
+        for c in s.trim().chars().filter(|&c| c != '\u{fe0f}') {
+            placements.push(Placement::try_from(c)?);
+        }
+
+        Pattern::new(&placements).ok_or(PatternError::Size(placements.len()))
+    }
+}
+
+impl From<Pattern> for String {
+    fn from(pattern: Pattern) -> Self {
+        pattern.to_string()
+    }
+}
+
+/// Errors that may occur when parsing a [`Pattern`] or creating a guess from one.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatternError {
+    /// Occurs when a pattern doesn't have one placement per letter of the word it's for.
+    #[error("Pattern must be exactly {expected} letters, found {found}")]
+    Length { expected: usize, found: usize },
+
+    /// Occurs when a pattern is empty or longer than any word.
+    #[error("Pattern must be between 1 and {MAX_WORD_LENGTH} letters, found {0}")]
+    Size(usize),
+
+    /// Occurs when a pattern contains something other than a placement.
+    #[error("Pattern must only contain B/Y/G, 0/1/2 or colored squares, found {0:?}")]
+    Placement(char),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::share::Theme;
+    use Placement::{Correct as G, Incorrect as B, Misplaced as Y};
+
+    #[test]
+    fn parse_accepts_every_format() {
+        let expected = Pattern::new(&[B, Y, G, B, B]).unwrap();
+        for input in [
+            "BYGBB",
+            "bygbb",
+            "01200",
+            "⬛🟨🟩⬛⬛",
+            "⬜🟦🟧⬜⬜",
+            " ⬛️🟨🟩⬛️⬛️\n",
+            "B1🟩bB",
+        ] {
+            assert_eq!(input.parse::<Pattern>(), Ok(expected), "{input}");
+        }
+
+        assert_eq!("".parse::<Pattern>(), Err(PatternError::Size(0)));
+        assert_eq!("BBBBBBBBB".parse::<Pattern>(), Err(PatternError::Size(9)));
+        assert_eq!("BYX".parse::<Pattern>(), Err(PatternError::Placement('X')));
+    }
+
+    #[test]
+    fn format_works() {
+        let pattern = Pattern::new(&[B, Y, G, B, B]).unwrap();
+        assert_eq!(pattern.to_string(), "BYGBB");
+        assert_eq!(pattern.to_digits(), "01200");
+        assert_eq!(pattern.to_emoji(ShareStyle::default()), "⬛🟨🟩⬛⬛");
+
+        let light = ShareStyle {
+            theme: Theme::Light,
+            high_contrast: true,
+        };
+        assert_eq!(pattern.to_emoji(light), "⬜🟦🟧⬜⬜");
+        assert_eq!(format!("{pattern:?}"), r#"Pattern("BYGBB")"#);
+    }
+
+    #[test]
+    fn encode_round_trips() {
+        assert_eq!(Pattern::count(5), 243);
+        assert_eq!("BYGBB".parse::<Pattern>().unwrap().encode(), 45);
+        assert_eq!(Pattern::solved(5).encode(), 242);
+
+        for length in 1..=MAX_WORD_LENGTH {
+            let codes = (0..Pattern::count(length)).map(|c| u16::try_from(c).unwrap());
+            for code in codes {
+                let pattern = Pattern::decode(code, length).unwrap();
+                assert_eq!(pattern.len(), length);
+                assert_eq!(pattern.encode(), code);
+            }
+        }
+
+        assert_eq!(Pattern::decode(243, 5), None);
+        assert_eq!(Pattern::decode(0, 0), None);
+    }
+
+    #[test]
+    fn is_solved_works() {
+        assert!(Pattern::solved(6).is_solved());
+        assert!(!"GGGGY".parse::<Pattern>().unwrap().is_solved());
+    }
+}
@@ -1,5 +1,8 @@
+use crate::pattern::PatternError;
+use std::fmt::Display;
+
 /// An indicator of whether a letter in a guess is in the word.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Placement {
     /// The letter is not in the solution.
     Incorrect,
@@ -11,6 +14,33 @@ pub enum Placement {
     Correct,
 }
 
+impl Display for Placement {
+    /// Writes the placement as the color of its tile: `B`lack, `Y`ellow or `G`reen.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Placement::Incorrect => 'B',
+            Placement::Misplaced => 'Y',
+            Placement::Correct => 'G',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for Placement {
+    type Error = PatternError;
+
+    /// Converts a tile color (`B`/`Y`/`G`, case-insensitive), digit (`0`/`1`/`2`) or emoji square
+    /// in any [`ShareStyle`](crate::share::ShareStyle) to a placement.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'B' | 'b' | '0' | '⬛' | '⬜' => Ok(Placement::Incorrect),
+            'Y' | 'y' | '1' | '🟨' | '🟦' => Ok(Placement::Misplaced),
+            'G' | 'g' | '2' | '🟩' | '🟧' => Ok(Placement::Correct),
+            _ => Err(PatternError::Placement(c)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,747 @@
+//! [`serde`] support, enabled by the `serde` feature.
+//!
+//! Everything is serialized in a stable, human-readable form: words are strings, placements are
+//! `B`lack, `Y`ellow or `G`reen, and guesses are a word along with its pattern of placements:
+//!
+//! ```json
+//! {
+//!   "solution": "hunky",
+//!   "solution_choice": "random",
+//!   "guesses": [{ "word": "salet", "pattern": "BBBBB" }],
+//!   "hard_mode": false,
+//!   "max_guesses": 6
+//! }
+//! ```
+//!
+//! The `solution_choice` is how the solution was chosen: `"random"`, `{ "seeded": 42 }`,
+//! `{ "daily": "2025-01-01" }` or `"fixed"`. A `max_guesses` of `null` means the game has
+//! unlimited guesses. Games played with any other dictionary than the
+//! [standard](Dictionary::standard) one also have a `dictionary`, with the name of its alphabet
+//! and its word lists:
+//!
+//! ```json
+//! { "alphabet": "Spanish", "solutions": ["niño", "peña"], "guesses": [] }
+//! ```
+//!
+//! Games are checked to be consistent with their solution (and rules) as they're replayed, and
+//! solutions with the way they were chosen.
+
+use crate::daily::Date;
+use crate::game::{GameConfig, GameOutcome, MAX_GUESSES, SolutionChoice};
+use crate::letter_bank::LetterBank;
+use crate::pattern::Pattern;
+use crate::word::WordError;
+use crate::{Alphabet, Dictionary, Game, Guess, Placement, PlayingGame, Word};
+use rand::{SeedableRng as _, rngs::StdRng};
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+impl Serialize for Word {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Word {
+    /// Deserializes any word made of letters that can be stored, whether or not it's in a
+    /// dictionary.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let word = String::deserialize(deserializer)?;
+        Word::parse(&word, None, Alphabet::LATIN_1).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Placement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Placement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let c = char::deserialize(deserializer)?;
+        Placement::try_from(c).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    /// Deserializes a pattern written in any of the forms [`Pattern`]'s `FromStr` accepts.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        pattern.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Alphabet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for &'static Alphabet {
+    /// Deserializes a [builtin](Alphabet::BUILTIN) alphabet from its name.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Alphabet::named(&name).ok_or_else(|| de::Error::custom(format!("unknown alphabet {name}")))
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    /// Deserializes a date written as `YYYY-MM-DD`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        date.parse().map_err(de::Error::custom)
+    }
+}
+
+/// The serialized form of a [`Dictionary`].
+#[derive(Serialize, Deserialize)]
+struct SavedDictionary<W = Vec<Word>> {
+    alphabet: &'static Alphabet,
+    solutions: W,
+    guesses: W,
+}
+
+impl Serialize for Dictionary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedDictionary {
+            alphabet: self.alphabet(),
+            solutions: self.solutions(),
+            guesses: self.guesses(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dictionary {
+    /// Deserializes a dictionary, checking that every word is made of letters in its alphabet.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SavedDictionary {
+            alphabet,
+            solutions,
+            guesses,
+        } = SavedDictionary::deserialize(deserializer)?;
+
+        let words = |words: Vec<Word>| words.into_iter().map(|w| w.as_str().to_owned());
+        Dictionary::with_alphabet(alphabet, words(solutions), words(guesses))
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Returns the dictionary a game is saved with, which is `None` for the standard dictionary.
+///
+/// Games usually share the standard dictionary, so its words are only compared if it's a copy.
+fn saved_dictionary(dictionary: &Arc<Dictionary>) -> Option<&Dictionary> {
+    let standard = Dictionary::standard();
+    let is_standard = Arc::ptr_eq(dictionary, &standard) || **dictionary == *standard;
+    (!is_standard).then_some(dictionary)
+}
+
+/// Returns the dictionary a game was saved with.
+///
+/// Games saved without one were played with the standard dictionary, so their solution must be
+/// in it: anything else was saved from another dictionary that's been lost.
+fn restored_dictionary<E: de::Error>(
+    dictionary: Option<Dictionary>,
+    solution: Word,
+) -> Result<Arc<Dictionary>, E> {
+    match dictionary {
+        Some(dictionary) => Ok(Arc::new(dictionary)),
+        None if Dictionary::standard().contains(solution) => Ok(Dictionary::standard()),
+        None => Err(E::custom(format!(
+            "game has no dictionary, and its solution {solution} isn't in the standard one"
+        ))),
+    }
+}
+
+/// The serialized form of a [`Guess`].
+#[derive(Serialize, Deserialize)]
+struct SavedGuess {
+    word: Word,
+    pattern: Pattern,
+}
+
+impl Serialize for Guess {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedGuess {
+            word: *self.word(),
+            pattern: self.pattern(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Guess {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SavedGuess { word, pattern } = SavedGuess::deserialize(deserializer)?;
+
+        Guess::with_pattern(word, pattern).map_err(de::Error::custom)
+    }
+}
+
+/// The serialized form of a [`LetterBank`]: its alphabet, and a map from each letter with a known
+/// placement to that placement.
+#[derive(Serialize, Deserialize)]
+struct SavedLetterBank {
+    alphabet: &'static Alphabet,
+    letters: BTreeMap<char, Placement>,
+}
+
+impl Serialize for LetterBank {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let letters = self
+            .alphabet()
+            .letters()
+            .filter_map(|letter| Some((char::from(letter), self.get(&letter)?)))
+            .collect();
+
+        SavedLetterBank {
+            alphabet: self.alphabet(),
+            letters,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LetterBank {
+    /// Deserializes a letter bank, checking that every known letter is in its alphabet.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SavedLetterBank { alphabet, letters } = SavedLetterBank::deserialize(deserializer)?;
+
+        let mut letter_bank = LetterBank::with_alphabet(alphabet);
+        for (letter, placement) in letters {
+            let Some(letter) = u8::try_from(letter).ok().filter(|&l| alphabet.contains(l)) else {
+                return Err(de::Error::custom(format!(
+                    "{letter:?} isn't a letter in the {} alphabet",
+                    alphabet.name()
+                )));
+            };
+            letter_bank.set(letter, placement);
+        }
+
+        Ok(letter_bank)
+    }
+}
+
+/// The serialized form of a [`SolutionChoice`], without the solution it chose (which is saved
+/// alongside it).
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SavedChoice {
+    Random,
+    Seeded(u64),
+    Daily(Date),
+    /// Games saved before their solution choice was saved had a fixed solution.
+    #[default]
+    Fixed,
+}
+
+impl From<SolutionChoice> for SavedChoice {
+    fn from(choice: SolutionChoice) -> Self {
+        match choice {
+            SolutionChoice::Random => SavedChoice::Random,
+            SolutionChoice::Seeded(seed) => SavedChoice::Seeded(seed),
+            SolutionChoice::Daily(date) => SavedChoice::Daily(date),
+            SolutionChoice::Fixed(_) => SavedChoice::Fixed,
+        }
+    }
+}
+
+impl SavedChoice {
+    /// Restores the way a saved game's solution was chosen, checking that it chooses the saved
+    /// solution.
+    fn restore<E: de::Error>(
+        self,
+        solution: Word,
+        dictionary: &Dictionary,
+    ) -> Result<SolutionChoice, E> {
+        let (choice, chosen) = match self {
+            SavedChoice::Random => (SolutionChoice::Random, None),
+            SavedChoice::Seeded(seed) => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let chosen = dictionary.random_solution_with(&mut rng);
+                (SolutionChoice::Seeded(seed), Some(chosen))
+            }
+            SavedChoice::Daily(date) => {
+                let chosen = dictionary.daily_solution(date);
+                (SolutionChoice::Daily(date), Some(chosen))
+            }
+            SavedChoice::Fixed => (SolutionChoice::Fixed(solution), None),
+        };
+
+        match chosen {
+            Some(chosen) if chosen != solution => Err(E::custom(format!(
+                "solution {solution} isn't the one chosen for the game ({chosen})"
+            ))),
+            _ => Ok(choice),
+        }
+    }
+}
+
+/// The serialized form of a [`PlayingGame`] or [`GameOutcome`].
+#[derive(Serialize, Deserialize)]
+struct SavedGame<D = Dictionary> {
+    #[serde(default = "Option::default", skip_serializing_if = "Option::is_none")]
+    dictionary: Option<D>,
+    solution: Word,
+    #[serde(default)]
+    solution_choice: SavedChoice,
+    guesses: Vec<Guess>,
+    #[serde(default)]
+    hard_mode: bool,
+    #[serde(default = "default_max_guesses")]
+    max_guesses: Option<usize>,
+}
+
+/// Games saved before they could have unlimited guesses had the classic number.
+#[allow(clippy::unnecessary_wraps)]
+fn default_max_guesses() -> Option<usize> {
+    Some(MAX_GUESSES)
+}
+
+impl<'a> SavedGame<&'a Dictionary> {
+    fn new(config: &'a GameConfig, solution: Word, guesses: &[Guess]) -> Self {
+        SavedGame {
+            dictionary: saved_dictionary(config.dictionary()),
+            solution,
+            solution_choice: config.solution().into(),
+            guesses: guesses.to_vec(),
+            hard_mode: config.is_hard_mode(),
+            max_guesses: config.max_guesses(),
+        }
+    }
+}
+
+impl SavedGame {
+    /// Restores the configuration a saved game was played with, along with its solution and
+    /// guesses.
+    fn restore<E: de::Error>(self) -> Result<(GameConfig, Word, Vec<Guess>), E> {
+        let dictionary = restored_dictionary(self.dictionary, self.solution)?;
+        if self.solution.len() != dictionary.word_length() {
+            return Err(E::custom(WordError::Length(dictionary.word_length())));
+        }
+
+        let choice = self.solution_choice.restore(self.solution, &dictionary)?;
+        let config = GameConfig::with_dictionary(dictionary)
+            .with_solution(choice)
+            .with_hard_mode(self.hard_mode);
+        let config = match self.max_guesses {
+            Some(max_guesses) => config.with_max_guesses(max_guesses),
+            None => config.with_unlimited_guesses(),
+        };
+
+        Ok((config, self.solution, self.guesses))
+    }
+}
+
+impl Serialize for PlayingGame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedGame::new(self.config(), self.solution, self.guesses()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayingGame {
+    /// Deserializes a game by replaying its guesses, so its letter bank is rebuilt and every guess
+    /// is checked against the solution and the game's rules.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved: SavedGame = SavedGame::deserialize(deserializer)?;
+        match replay(saved)? {
+            Game::Playing(game) => Ok(game),
+            Game::Over(_) => Err(de::Error::custom("game is already over")),
+        }
+    }
+}
+
+impl Serialize for GameOutcome {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SavedGame::new(self.config(), *self.solution(), self.guesses()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameOutcome {
+    /// Deserializes a finished game by replaying its guesses, so every guess is checked against
+    /// the solution and the game's rules, and the game must end with its last guess.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved: SavedGame = SavedGame::deserialize(deserializer)?;
+        match replay(saved)? {
+            Game::Over(outcome) => Ok(outcome),
+            Game::Playing(_) => Err(de::Error::custom("game isn't over")),
+        }
+    }
+}
+
+/// The serialized form of a [`Game`], tagged with its state.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "lowercase")]
+enum SavedState<P = PlayingGame, O = GameOutcome> {
+    Playing(P),
+    Over(O),
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Game::Playing(game) => SavedState::<_, &GameOutcome>::Playing(game),
+            Game::Over(outcome) => SavedState::<&PlayingGame, _>::Over(outcome),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(
+            match SavedState::<PlayingGame, GameOutcome>::deserialize(deserializer)? {
+                SavedState::Playing(game) => Game::Playing(game),
+                SavedState::Over(outcome) => Game::Over(outcome),
+            },
+        )
+    }
+}
+
+/// Replays a saved game's guesses against its solution.
+fn replay<E: de::Error>(saved: SavedGame) -> Result<Game, E> {
+    let (config, solution, guesses) = saved.restore()?;
+    let mut game = Game::Playing(PlayingGame::new_unchecked(config, solution));
+
+    for guess in guesses {
+        let Game::Playing(playing) = game else {
+            return Err(E::custom("game continues after it's over"));
+        };
+
+        game = playing.guess(*guess.word()).map_err(E::custom)?;
+        let replayed = match &game {
+            Game::Playing(playing) => playing.guesses().last(),
+            Game::Over(outcome) => outcome.guesses().last(),
+        };
+
+        if replayed != Some(&guess) {
+            let word = guess.word();
+            return Err(E::custom(format!("guess {word} doesn't match solution")));
+        }
+    }
+
+    Ok(game)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn word(s: &str) -> Word {
+        Word::new(s).unwrap()
+    }
+
+    fn playing(solution: &str, guesses: &[&str]) -> PlayingGame {
+        let mut game = PlayingGame::with_solution(Dictionary::standard(), word(solution))
+            .unwrap()
+            .with_hard_mode(true);
+
+        for guess in guesses {
+            let Ok(Game::Playing(next)) = game.guess(word(guess)) else {
+                panic!("game should still be playing after {guess}");
+            };
+            game = next;
+        }
+
+        game
+    }
+
+    /// Starts a game with a dictionary of solutions, making some guesses.
+    fn game_in(solutions: &[&str], solution: &str, guesses: &[&str]) -> PlayingGame {
+        let dictionary = Dictionary::new(solutions, std::iter::empty::<&str>()).unwrap();
+        let word = |w| Word::with_dictionary(w, &dictionary).unwrap();
+        let solution = word(solution);
+        let guesses: Vec<Word> = guesses.iter().map(|w| word(w)).collect();
+
+        let mut game = PlayingGame::with_solution(Arc::new(dictionary), solution).unwrap();
+        for guess in guesses {
+            let Ok(Game::Playing(next)) = game.guess(guess) else {
+                panic!("game should still be playing after {guess}");
+            };
+            game = next;
+        }
+
+        game
+    }
+
+    #[test]
+    fn words_and_placements_are_strings() {
+        assert_eq!(serde_json::to_value(word("crane")).unwrap(), json!("crane"));
+        assert_eq!(
+            serde_json::from_value::<Word>(json!("Crane")).unwrap(),
+            word("crane")
+        );
+        assert!(serde_json::from_value::<Word>(json!("cr4ne")).is_err());
+
+        assert_eq!(
+            serde_json::to_value([
+                Placement::Incorrect,
+                Placement::Misplaced,
+                Placement::Correct
+            ])
+            .unwrap(),
+            json!(["B", "Y", "G"])
+        );
+        assert!(serde_json::from_value::<Placement>(json!("X")).is_err());
+    }
+
+    #[test]
+    fn guesses_round_trip() {
+        let solution = word("hunky");
+        for guess in ["salet", "courd", "hunky"] {
+            let guess = Guess::new(&solution, word(guess));
+            let value = serde_json::to_value(guess).unwrap();
+            assert_eq!(serde_json::from_value::<Guess>(value).unwrap(), guess);
+        }
+
+        let value = serde_json::to_value(Guess::new(&solution, word("courd"))).unwrap();
+        assert_eq!(value, json!({ "word": "courd", "pattern": "BBYBB" }));
+
+        let invalid = json!({ "word": "courd", "pattern": "BBYB" });
+        assert!(serde_json::from_value::<Guess>(invalid).is_err());
+    }
+
+    #[test]
+    fn letter_banks_round_trip() {
+        let game = playing("hunky", &["salet", "courd"]);
+        let value = serde_json::to_value(game.letter_bank()).unwrap();
+        assert_eq!(value["alphabet"], json!("English"));
+        assert_eq!(value["letters"]["u"], json!("Y"));
+        assert_eq!(value["letters"]["s"], json!("B"));
+        assert!(value["letters"].get("h").is_none());
+
+        let letter_bank: LetterBank = serde_json::from_value(value).unwrap();
+        assert_eq!(&letter_bank, game.letter_bank());
+    }
+
+    #[test]
+    fn accented_letters_round_trip() {
+        let dictionary = Dictionary::new(["niño", "peña"], std::iter::empty::<&str>());
+        let dictionary = dictionary.unwrap();
+        let word = |w| Word::with_dictionary(w, &dictionary).unwrap();
+
+        let mut letter_bank = LetterBank::with_alphabet(dictionary.alphabet());
+        letter_bank.report_guess(&Guess::new(&word("peña"), word("niño")));
+        let value = serde_json::to_value(&letter_bank).unwrap();
+        assert_eq!(value["letters"]["ñ"], json!("G"));
+
+        let restored: LetterBank = serde_json::from_value(value).unwrap();
+        assert_eq!(restored, letter_bank);
+
+        // The alphabet is kept even when none of its accented letters have been guessed.
+        let unguessed = serde_json::to_value(LetterBank::with_alphabet(Alphabet::SPANISH)).unwrap();
+        let restored: LetterBank = serde_json::from_value(unguessed).unwrap();
+        assert_eq!(restored.alphabet(), Alphabet::SPANISH);
+
+        let invalid = json!({ "alphabet": "English", "letters": { "ñ": "G" } });
+        assert!(serde_json::from_value::<LetterBank>(invalid).is_err());
+
+        assert_eq!(serde_json::to_value(word("niño")).unwrap(), json!("niño"));
+        assert_eq!(
+            serde_json::from_value::<Word>(json!("NIN\u{303}O")).unwrap(),
+            word("niño")
+        );
+    }
+
+    #[test]
+    fn games_round_trip() {
+        let game = playing("lemon", &["salet", "melon"]);
+        let json = serde_json::to_string(&Game::Playing(game.clone())).unwrap();
+        let Game::Playing(restored) = serde_json::from_str(&json).unwrap() else {
+            panic!("game should still be playing");
+        };
+
+        assert_eq!(restored.solution, game.solution);
+        assert_eq!(restored.guesses(), game.guesses());
+        assert_eq!(restored.letter_bank(), game.letter_bank());
+        assert!(restored.is_hard_mode());
+
+        let Ok(Game::Over(outcome)) = restored.guess(word("lemon")) else {
+            panic!("game should be over");
+        };
+        let json = serde_json::to_string(&Game::Over(outcome.clone())).unwrap();
+        let Game::Over(restored) = serde_json::from_str(&json).unwrap() else {
+            panic!("game should be over");
+        };
+
+        assert_eq!(restored.solution(), outcome.solution());
+        assert_eq!(restored.guesses(), outcome.guesses());
+        assert!(restored.won());
+        assert!(restored.config().is_hard_mode());
+    }
+
+    #[test]
+    fn outcomes_keep_their_dictionary_and_solution_choice() {
+        let game = game_in(&["peña", "niño"], "peña", &[]);
+        let solution = game.solution;
+        let Ok(Game::Over(outcome)) = game.guess(solution) else {
+            panic!("game should be over");
+        };
+        let value = serde_json::to_value(&outcome).unwrap();
+        let restored: GameOutcome = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.config().dictionary().alphabet(), Alphabet::SPANISH);
+        assert_eq!(
+            restored.config().dictionary(),
+            outcome.config().dictionary()
+        );
+
+        let date = crate::daily::Date::new(2025, 1, 1).unwrap();
+        let daily = PlayingGame::daily(Dictionary::standard(), date);
+        let solution = daily.solution;
+        let Ok(Game::Over(outcome)) = daily.guess(solution) else {
+            panic!("game should be over");
+        };
+        let mut value = serde_json::to_value(&outcome).unwrap();
+        assert_eq!(value["solution_choice"], json!({ "daily": "2025-01-01" }));
+
+        let restored: GameOutcome = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(restored.config().solution(), SolutionChoice::Daily(date));
+
+        // The saved solution must be the one its choice chooses.
+        value["solution_choice"] = json!({ "daily": "2025-01-02" });
+        assert!(serde_json::from_value::<GameOutcome>(value).is_err());
+
+        let seeded = GameConfig::new().with_solution(SolutionChoice::Seeded(7));
+        let game = PlayingGame::with_config(seeded).unwrap();
+        let value = serde_json::to_value(&game).unwrap();
+        assert_eq!(value["solution_choice"], json!({ "seeded": 7 }));
+        let restored: PlayingGame = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.config().solution(), SolutionChoice::Seeded(7));
+    }
+
+    #[test]
+    fn unlimited_guesses_round_trip() {
+        let game = playing("lemon", &["salet"]).with_unlimited_guesses();
+        let value = serde_json::to_value(&game).unwrap();
+        assert_eq!(value["max_guesses"], json!(null));
+
+        let restored: PlayingGame = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.max_guesses(), None);
+
+        let outcome = json!({
+            "solution": "hunky",
+            "guesses": [{ "word": "hunky", "pattern": "GGGGG" }],
+            "max_guesses": null,
+        });
+        let outcome: GameOutcome = serde_json::from_value(outcome).unwrap();
+        assert_eq!(outcome.config().max_guesses(), None);
+        assert_eq!(
+            outcome.config().solution(),
+            SolutionChoice::Fixed(word("hunky"))
+        );
+    }
+
+    #[test]
+    fn dictionaries_round_trip() {
+        let value = serde_json::to_value(game_in(&["peña", "niño"], "peña", &["niño"])).unwrap();
+        assert_eq!(
+            value["dictionary"],
+            json!({ "alphabet": "Spanish", "solutions": ["niño", "peña"], "guesses": [] })
+        );
+
+        let restored: PlayingGame = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.dictionary().alphabet(), Alphabet::SPANISH);
+        assert_eq!(restored.dictionary().word_length(), 4);
+        assert_eq!(restored.guesses().len(), 1);
+        assert_eq!(
+            restored.letter_bank().get(&b'\xf1'),
+            Some(Placement::Correct)
+        );
+
+        let standard = serde_json::to_value(playing("lemon", &["salet"])).unwrap();
+        assert!(standard.get("dictionary").is_none());
+
+        // A game without its dictionary is never replayed with the standard one instead.
+        let mut missing = serde_json::to_value(game_in(&["cats", "dogs"], "dogs", &[])).unwrap();
+        missing.as_object_mut().unwrap().remove("dictionary");
+        assert!(serde_json::from_value::<PlayingGame>(missing).is_err());
+
+        let unknown = json!({ "alphabet": "Klingon", "solutions": ["qapla"], "guesses": [] });
+        assert!(serde_json::from_value::<crate::Dictionary>(unknown).is_err());
+    }
+
+    #[test]
+    fn inconsistent_games_are_rejected() {
+        let tampered = json!({
+            "solution": "hunky",
+            "guesses": [{ "word": "salet", "pattern": "GBBBB" }],
+        });
+        assert!(serde_json::from_value::<PlayingGame>(tampered.clone()).is_err());
+        assert!(serde_json::from_value::<GameOutcome>(tampered).is_err());
+
+        // crane reveals the final E of those, which hard mode requires to be reused
+        let illegal = json!({
+            "solution": "those",
+            "guesses": [
+                { "word": "crane", "pattern": "BBBBG" },
+                { "word": "salet", "pattern": "YBBYY" },
+            ],
+            "hard_mode": true,
+        });
+        assert!(serde_json::from_value::<PlayingGame>(illegal).is_err());
+
+        let too_long = json!({
+            "solution": "hunky",
+            "guesses": [
+                { "word": "salet", "pattern": "BBBBB" },
+                { "word": "hunky", "pattern": "GGGGG" },
+            ],
+            "max_guesses": 1,
+        });
+        assert!(serde_json::from_value::<GameOutcome>(too_long).is_err());
+    }
+
+    #[test]
+    fn unfinished_or_illegal_outcomes_are_rejected() {
+        let after_win = json!({
+            "solution": "hunky",
+            "guesses": [
+                { "word": "hunky", "pattern": "GGGGG" },
+                { "word": "salet", "pattern": "BBBBB" },
+            ],
+        });
+        assert!(serde_json::from_value::<GameOutcome>(after_win).is_err());
+
+        let empty = json!({ "solution": "hunky", "guesses": [] });
+        assert!(serde_json::from_value::<GameOutcome>(empty).is_err());
+
+        let unfinished = json!({
+            "solution": "hunky",
+            "guesses": [{ "word": "salet", "pattern": "BBBBB" }],
+        });
+        assert!(serde_json::from_value::<GameOutcome>(unfinished).is_err());
+
+        // crane reveals the final E of those, which hard mode requires to be reused
+        let illegal = json!({
+            "solution": "those",
+            "guesses": [
+                { "word": "crane", "pattern": "BBBBG" },
+                { "word": "salet", "pattern": "YBBYY" },
+                { "word": "those", "pattern": "GGGGG" },
+            ],
+            "hard_mode": true,
+        });
+        assert!(serde_json::from_value::<GameOutcome>(illegal.clone()).is_err());
+
+        let mut legal = illegal;
+        legal["hard_mode"] = json!(false);
+        assert!(serde_json::from_value::<GameOutcome>(legal).is_ok());
+    }
+}
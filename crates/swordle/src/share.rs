@@ -0,0 +1,444 @@
+//! Emoji share grids, like the ones posted after finishing a game:
+//!
+//! ```text
+//! Wordle 1,234 3/6
+//!
+//! ⬛⬛🟨⬛⬛
+//! 🟨🟩⬛🟨⬛
+//! 🟩🟩🟩🟩🟩
+//! ```
+
+use crate::game::GameOutcome;
+use crate::word::MAX_WORD_LENGTH;
+use crate::{Guess, Placement};
+use std::fmt::Write as _;
+
+/// The background color the grid's incorrect squares should blend into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Incorrect letters are black squares.
+    #[default]
+    Dark,
+
+    /// Incorrect letters are white squares.
+    Light,
+}
+
+/// How to draw a share grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShareStyle {
+    pub theme: Theme,
+
+    /// Whether to draw correct letters orange and misplaced letters blue instead of green and
+    /// yellow, for colorblind players.
+    pub high_contrast: bool,
+}
+
+impl ShareStyle {
+    /// Returns the square a placement is drawn with.
+    #[must_use]
+    pub fn square(self, placement: Placement) -> char {
+        match (placement, self.high_contrast, self.theme) {
+            (Placement::Correct, false, _) => '🟩',
+            (Placement::Correct, true, _) => '🟧',
+            (Placement::Misplaced, false, _) => '🟨',
+            (Placement::Misplaced, true, _) => '🟦',
+            (Placement::Incorrect, _, Theme::Dark) => '⬛',
+            (Placement::Incorrect, _, Theme::Light) => '⬜',
+        }
+    }
+}
+
+/// Renders the share text for a finished game: a `Wordle <puzzle> <score>/<max guesses>` header
+/// followed by a row of squares for each guess.
+///
+/// The puzzle number is left out of the header if there isn't one, and lost games score `X`.
+/// Games with unlimited guesses are scored out of `∞`, and hard mode games are marked with `*`.
+pub(crate) fn render(outcome: &GameOutcome, puzzle: Option<i64>, style: ShareStyle) -> String {
+    let mut text = String::from("Wordle");
+    if let Some(puzzle) = puzzle {
+        write!(text, " {}", group_thousands(puzzle)).expect("writing to a string can't fail");
+    }
+
+    let score = outcome.won().then_some(outcome.guesses().len());
+    let config = outcome.config();
+    let result = format_result(score, config.max_guesses(), config.is_hard_mode());
+    write!(text, " {result}\n\n").expect("writing to a string can't fail");
+
+    for guess in outcome.guesses() {
+        text.extend(guess.placements().iter().map(|&p| style.square(p)));
+        text.push('\n');
+    }
+
+    text
+}
+
+/// A share grid parsed back from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedGrid {
+    /// The puzzle number from the header, if there was one.
+    pub puzzle: Option<i64>,
+
+    /// Whether the header marked the game as played in hard mode (with a trailing `*`).
+    pub hard_mode: bool,
+
+    /// The placements revealed by each guess, in order.
+    pub rows: Vec<Vec<Placement>>,
+}
+
+impl SharedGrid {
+    /// Returns whether the final guess found the solution.
+    #[must_use]
+    pub fn won(&self) -> bool {
+        self.rows
+            .last()
+            .is_some_and(|row| row.iter().all(|&p| p == Placement::Correct))
+    }
+
+    /// Returns the rows as guesses' placements, padded to [`MAX_WORD_LENGTH`] like
+    /// [`Guess::Incorrect`]'s.
+    pub fn placements(&self) -> impl Iterator<Item = [Placement; MAX_WORD_LENGTH]> {
+        self.rows.iter().map(|row| {
+            let mut placements = [Placement::Incorrect; MAX_WORD_LENGTH];
+            placements[..row.len()].copy_from_slice(row);
+            placements
+        })
+    }
+
+    /// Returns whether a guess revealed the same placements as a row of the grid.
+    #[must_use]
+    pub fn row_matches(&self, row: usize, guess: &Guess) -> bool {
+        self.rows.get(row).is_some_and(|r| r == guess.placements())
+    }
+}
+
+/// Parses a pasted share grid, in any [`ShareStyle`].
+///
+/// The `Wordle <puzzle> <score>/6` header is optional, but when present its score must match the
+/// grid. Blank lines and whitespace around the grid are ignored.
+///
+/// # Errors
+///
+/// Returns a [`ShareError`] if the text doesn't contain a valid grid.
+pub fn parse(text: &str) -> Result<SharedGrid, ShareError> {
+    let mut grid = SharedGrid {
+        puzzle: None,
+        hard_mode: false,
+        rows: Vec::new(),
+    };
+    let mut score = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("Wordle") {
+            if score.is_some() || !grid.rows.is_empty() {
+                return Err(ShareError::Header { line: line_number });
+            }
+
+            let (puzzle, header_score, hard_mode) =
+                parse_header(line).ok_or(ShareError::Header { line: line_number })?;
+            (grid.puzzle, score, grid.hard_mode) = (puzzle, Some(header_score), hard_mode);
+            continue;
+        }
+
+        let row = line
+            .chars()
+            .filter(|&c| c != '\u{fe0f}')
+            .map(|c| {
+                square_to_placement(c).ok_or(ShareError::Square {
+                    line: line_number,
+                    found: c,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expected = grid.rows.first().map_or(row.len(), Vec::len);
+        if row.len() != expected || row.len() > MAX_WORD_LENGTH {
+            return Err(ShareError::RowLength {
+                line: line_number,
+                found: row.len(),
+            });
+        }
+
+        grid.rows.push(row);
+    }
+
+    if grid.rows.is_empty() {
+        return Err(ShareError::Empty);
+    }
+
+    if let Some(score) = score {
+        let expected = if grid.won() {
+            Some(grid.rows.len())
+        } else {
+            None
+        };
+        if score != expected {
+            return Err(ShareError::Score);
+        }
+    }
+
+    Ok(grid)
+}
+
+/// Parses a `Wordle 1,234 4/6*` header into its puzzle number, score (`None` for `X`) and whether
+/// it was played in hard mode.
+fn parse_header(line: &str) -> Option<(Option<i64>, Option<usize>, bool)> {
+    let mut tokens = line.split_whitespace().skip(1).collect::<Vec<_>>();
+    let result = tokens.pop()?;
+
+    let puzzle = match tokens.as_slice() {
+        [] => None,
+        [puzzle] => Some(puzzle.replace([',', '.', '#'], "").parse().ok()?),
+        _ => return None,
+    };
+
+    let (score, _max_guesses, hard_mode) = parse_result(result)?;
+    Some((puzzle, score, hard_mode))
+}
+
+/// Formats a game's result like `4/6`: its score (`X` if it was lost) out of its maximum number
+/// of guesses (`∞` if unlimited), followed by `*` if it was played in hard mode.
+pub(crate) fn format_result(
+    score: Option<usize>,
+    max_guesses: Option<usize>,
+    hard: bool,
+) -> String {
+    let score = score.map_or_else(|| "X".to_owned(), |score| score.to_string());
+    let max_guesses = max_guesses.map_or_else(|| "∞".to_owned(), |max| max.to_string());
+    let hard_mode = if hard { "*" } else { "" };
+    format!("{score}/{max_guesses}{hard_mode}")
+}
+
+/// Parses a result formatted by [`format_result`] into its score, maximum number of guesses and
+/// whether it was played in hard mode.
+pub(crate) fn parse_result(result: &str) -> Option<(Option<usize>, Option<usize>, bool)> {
+    let (result, hard_mode) = match result.strip_suffix('*') {
+        Some(result) => (result, true),
+        None => (result, false),
+    };
+
+    let (score, max_guesses) = result.split_once('/')?;
+    let score = match score {
+        "X" => None,
+        score => Some(score.parse().ok()?),
+    };
+    let max_guesses = match max_guesses {
+        "∞" => None,
+        max_guesses => Some(max_guesses.parse().ok()?),
+    };
+
+    Some((score, max_guesses, hard_mode))
+}
+
+/// Converts an emoji square in any style to its placement. The tile colors and digits a
+/// placement can also be written as aren't squares, and are all ASCII.
+fn square_to_placement(square: char) -> Option<Placement> {
+    Placement::try_from(square)
+        .ok()
+        .filter(|_| !square.is_ascii())
+}
+
+/// Formats a number with commas between groups of thousands, e.g. `1,234`.
+fn group_thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if n < 0 {
+        grouped.push('-');
+    }
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+/// Errors that may occur when parsing a share grid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ShareError {
+    /// Occurs when the text has no rows of squares.
+    #[error("Share grid must contain at least one row")]
+    Empty,
+
+    /// Occurs when a header isn't formatted like `Wordle 1,234 4/6`, or comes after the grid.
+    #[error("line {line}: invalid header")]
+    Header { line: usize },
+
+    /// Occurs when a row contains something other than a square.
+    #[error("line {line}: unexpected {found:?} in grid")]
+    Square { line: usize, found: char },
+
+    /// Occurs when a row's length differs from the first row's (or is too long to be a word).
+    #[error("line {line}: row has {found} squares")]
+    RowLength { line: usize, found: usize },
+
+    /// Occurs when the header's score doesn't match the grid.
+    #[error("Header score doesn't match the grid")]
+    Score,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{GameConfig, MAX_GUESSES};
+    use crate::{Dictionary, Word};
+
+    fn outcome(solution: &str, guesses: &[&str]) -> GameOutcome {
+        let solution = Word::new(solution).unwrap();
+        let guesses = guesses
+            .iter()
+            .map(|g| Guess::new(&solution, Word::new(g).unwrap()))
+            .collect();
+
+        GameOutcome::new(solution, guesses)
+    }
+
+    #[test]
+    fn render_works() {
+        let won = outcome("hunky", &["salet", "courd", "hunky"]);
+        assert_eq!(
+            won.share_text(Some(1234), ShareStyle::default()),
+            "Wordle 1,234 3/6\n\n⬛⬛⬛⬛⬛\n⬛⬛🟨⬛⬛\n🟩🟩🟩🟩🟩\n"
+        );
+
+        let light = ShareStyle {
+            theme: Theme::Light,
+            high_contrast: true,
+        };
+        assert_eq!(
+            won.share_text(None, light),
+            "Wordle 3/6\n\n⬜⬜⬜⬜⬜\n⬜⬜🟦⬜⬜\n🟧🟧🟧🟧🟧\n"
+        );
+
+        let lost = outcome("hunky", &["salet"; MAX_GUESSES]);
+        assert!(
+            lost.share_text(Some(7), light)
+                .starts_with("Wordle 7 X/6\n")
+        );
+    }
+
+    #[test]
+    fn render_shows_the_rules() {
+        let won = outcome("hunky", &["salet", "courd", "hunky"]);
+        let rules = GameConfig::with_dictionary(Dictionary::standard()).with_hard_mode(true);
+        let hard = GameOutcome::with_config(
+            *won.solution(),
+            won.guesses().into(),
+            rules.clone().with_max_guesses(4),
+        );
+        let text = hard.share_text(None, ShareStyle::default());
+        assert!(text.starts_with("Wordle 3/4*\n"), "{text}");
+        assert!(parse(&text).unwrap().hard_mode);
+
+        let unlimited = GameOutcome::with_config(
+            *won.solution(),
+            won.guesses().into(),
+            rules.with_hard_mode(false).with_unlimited_guesses(),
+        );
+        let text = unlimited.share_text(Some(2), ShareStyle::default());
+        assert!(text.starts_with("Wordle 2 3/∞\n"), "{text}");
+        assert_eq!(parse(&text).unwrap().rows.len(), 3);
+    }
+
+    #[test]
+    fn parse_round_trips_every_style() {
+        let won = outcome("lemon", &["salet", "crane", "melon", "lemon"]);
+        for theme in [Theme::Dark, Theme::Light] {
+            for high_contrast in [false, true] {
+                let style = ShareStyle {
+                    theme,
+                    high_contrast,
+                };
+                let grid = parse(&won.share_text(Some(1001), style)).unwrap();
+
+                assert_eq!(grid.puzzle, Some(1001));
+                assert!(!grid.hard_mode);
+                assert!(grid.won());
+                assert_eq!(grid.rows.len(), 4);
+                for (i, guess) in won.guesses().iter().enumerate() {
+                    assert!(grid.row_matches(i, guess));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_accepts_pasted_text() {
+        let grid = parse("  Wordle 1,234 X/6*\n\n⬛⬛🟨⬛⬛\n⬛️🟩⬛️🟨⬛️\n").unwrap();
+        assert_eq!(grid.puzzle, Some(1234));
+        assert!(grid.hard_mode);
+        assert!(!grid.won());
+        assert_eq!(
+            grid.placements().nth(1).unwrap()[..5],
+            [
+                Placement::Incorrect,
+                Placement::Correct,
+                Placement::Incorrect,
+                Placement::Misplaced,
+                Placement::Incorrect,
+            ]
+        );
+
+        assert_eq!(parse("🟩🟩🟩🟩🟩").unwrap().puzzle, None);
+    }
+
+    #[test]
+    fn parse_reports_errors() {
+        let cases: &[(&str, ShareError)] = &[
+            ("", ShareError::Empty),
+            ("Wordle 12 1/6", ShareError::Empty),
+            (
+                "Wordle twelve 1/6\n🟩🟩🟩🟩🟩",
+                ShareError::Header { line: 1 },
+            ),
+            ("🟩🟩🟩🟩🟩\nWordle 12 1/6", ShareError::Header { line: 2 }),
+            (
+                "⬛⬛x⬛⬛",
+                ShareError::Square {
+                    line: 1,
+                    found: 'x',
+                },
+            ),
+            (
+                "⬛⬛G⬛⬛",
+                ShareError::Square {
+                    line: 1,
+                    found: 'G',
+                },
+            ),
+            (
+                "⬛⬛⬛⬛⬛\n🟩🟩🟩🟩",
+                ShareError::RowLength { line: 2, found: 4 },
+            ),
+            ("Wordle 12 2/6\n🟩🟩🟩🟩🟩", ShareError::Score),
+            ("Wordle 12 1/6\n⬛⬛⬛⬛⬛", ShareError::Score),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse(input).as_ref(), Err(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn group_thousands_works() {
+        let cases = [
+            (0, "0"),
+            (999, "999"),
+            (1000, "1,000"),
+            (1_234_567, "1,234,567"),
+            (-1234, "-1,234"),
+        ];
+        for (n, expected) in cases {
+            assert_eq!(group_thousands(n), expected);
+        }
+    }
+}
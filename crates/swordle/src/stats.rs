@@ -0,0 +1,746 @@
+//! Player statistics kept across games: how many have been played and won, winning streaks, and
+//! how many guesses each win took.
+//!
+//! Every finished game is recorded as a line of a plain text history file, with the date it was
+//! played (or of its daily puzzle), its solution, its result (scored like a
+//! [share grid](crate::share)'s header) and how its solution was chosen, unless it was random:
+//!
+//! ```text
+//! 2025-01-01 crane 4/6 daily
+//! 2025-01-02 hunky X/6* seed:42
+//! 2025-01-02 lemon 3/∞
+//! ```
+//!
+//! Games played against a solution the player chose aren't recorded.
+//!
+//! History files can be copied between players to compare their statistics.
+
+use crate::daily::Date;
+use crate::game::SolutionChoice;
+use crate::share::{format_result, parse_result};
+use crate::{Alphabet, GameOutcome, Word};
+use colored::Colorize as _;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The width of the longest bar in a [guess distribution](Stats::render) histogram.
+const BAR_WIDTH: usize = 30;
+
+/// The number of rows a guess distribution always has, even if no game was won with that many
+/// guesses.
+const MIN_DISTRIBUTION_ROWS: usize = 6;
+
+/// How a recorded game's solution was chosen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Puzzle {
+    /// A random solution.
+    #[default]
+    Random,
+
+    /// A solution chosen using a seed, which replays the same game.
+    Seeded(u64),
+
+    /// The daily puzzle for the record's date.
+    Daily,
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Puzzle::Random => write!(f, "random"),
+            Puzzle::Seeded(seed) => write!(f, "seed:{seed}"),
+            Puzzle::Daily => write!(f, "daily"),
+        }
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "random" => Ok(Puzzle::Random),
+            "daily" => Ok(Puzzle::Daily),
+            _ => {
+                let seed = s.strip_prefix("seed:").ok_or(())?;
+                seed.parse().map(Puzzle::Seeded).map_err(|_| ())
+            }
+        }
+    }
+}
+
+/// A finished game, as recorded in a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    date: Date,
+    solution: Word,
+    score: Option<usize>,
+    max_guesses: Option<usize>,
+    hard_mode: bool,
+    puzzle: Puzzle,
+}
+
+impl Record {
+    /// Creates a record of a game finished on a given date. Daily puzzles are recorded with the
+    /// date of their puzzle instead.
+    ///
+    /// Returns `None` if the game had a [fixed](SolutionChoice::Fixed) solution, since the player
+    /// chose it.
+    #[must_use]
+    pub fn new(outcome: &GameOutcome, date: Date) -> Option<Self> {
+        let config = outcome.config();
+        let (date, puzzle) = match config.solution() {
+            SolutionChoice::Random => (date, Puzzle::Random),
+            SolutionChoice::Seeded(seed) => (date, Puzzle::Seeded(seed)),
+            SolutionChoice::Daily(puzzle_date) => (puzzle_date, Puzzle::Daily),
+            SolutionChoice::Fixed(_) => return None,
+        };
+
+        Some(Record {
+            date,
+            solution: *outcome.solution(),
+            score: outcome.won().then_some(outcome.guesses().len()),
+            max_guesses: config.max_guesses(),
+            hard_mode: config.is_hard_mode(),
+            puzzle,
+        })
+    }
+
+    /// Returns the date the game was played, or of its daily puzzle.
+    #[must_use]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    #[must_use]
+    pub fn puzzle(&self) -> Puzzle {
+        self.puzzle
+    }
+
+    pub fn solution(&self) -> &Word {
+        &self.solution
+    }
+
+    /// Returns the number of guesses it took to win the game, or `None` if it was lost.
+    #[must_use]
+    pub fn score(&self) -> Option<usize> {
+        self.score
+    }
+
+    #[must_use]
+    pub fn won(&self) -> bool {
+        self.score.is_some()
+    }
+
+    /// Returns the number of guesses the game allowed, or `None` if it was unlimited.
+    #[must_use]
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses
+    }
+
+    #[must_use]
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Returns whether two records are of the same game, i.e. the same daily puzzle or seeded
+    /// game with the same solution. Random games are never the same.
+    #[must_use]
+    pub fn is_same_game(&self, other: &Record) -> bool {
+        let same_puzzle = match (self.puzzle, other.puzzle) {
+            (Puzzle::Daily, Puzzle::Daily) => self.date == other.date,
+            (Puzzle::Seeded(seed), Puzzle::Seeded(other)) => seed == other,
+            _ => false,
+        };
+
+        same_puzzle && self.solution == other.solution
+    }
+
+    /// Parses a record from a line of a history file, e.g. `2025-01-01 crane 4/6 daily`.
+    fn parse(line: &str) -> Option<Self> {
+        let (date, solution, result, puzzle) = match line.split_whitespace().collect::<Vec<_>>()[..]
+        {
+            [date, solution, result] => (date, solution, result, Puzzle::Random),
+            [date, solution, result, puzzle] => (date, solution, result, puzzle.parse().ok()?),
+            _ => return None,
+        };
+
+        let (score, max_guesses, hard_mode) = parse_result(result)?;
+        let record = Record {
+            date: date.parse().ok()?,
+            solution: Word::parse(solution, None, Alphabet::LATIN_1).ok()?,
+            score,
+            max_guesses,
+            hard_mode,
+            puzzle,
+        };
+
+        let valid = match (score, max_guesses) {
+            (Some(score), Some(max_guesses)) => (1..=max_guesses).contains(&score),
+            (Some(score), None) => score > 0,
+            (None, max_guesses) => max_guesses.is_some(),
+        };
+        valid.then_some(record)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = format_result(self.score, self.max_guesses, self.hard_mode);
+        write!(f, "{} {} {result}", self.date, self.solution)?;
+        match self.puzzle {
+            Puzzle::Random => Ok(()),
+            puzzle => write!(f, " {puzzle}"),
+        }
+    }
+}
+
+/// Every game a player has finished, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Creates an empty history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a history file, which is treated as empty if it doesn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StatsError`] if the file can't be read or isn't a valid history.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StatsError> {
+        match fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Appends a record to a history file, creating the file (and its directory) if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StatsError`] if the file can't be written.
+    pub fn append(path: impl AsRef<Path>, record: &Record) -> Result<(), StatsError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{record}")?;
+        Ok(())
+    }
+
+    /// Adds a newly finished game to the history.
+    pub fn push(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    #[must_use]
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns whether the history already has a record of the same game (see
+    /// [`Record::is_same_game`]), e.g. because a daily puzzle was replayed.
+    #[must_use]
+    pub fn contains_game(&self, record: &Record) -> bool {
+        self.records.iter().any(|r| r.is_same_game(record))
+    }
+
+    /// Computes the statistics for every game in the history, as of a given date.
+    #[must_use]
+    pub fn stats(&self, today: Date) -> Stats {
+        Stats::from_records(&self.records, today)
+    }
+
+    /// Computes the statistics for the games in the history played by the same rules, so e.g.
+    /// wins with unlimited guesses don't count towards games allowing 6.
+    #[must_use]
+    pub fn stats_with_rules(
+        &self,
+        today: Date,
+        max_guesses: Option<usize>,
+        hard_mode: bool,
+    ) -> Stats {
+        let records = self
+            .records
+            .iter()
+            .filter(|r| r.max_guesses == max_guesses && r.hard_mode == hard_mode);
+        Stats::from_records(records, today)
+    }
+}
+
+impl FromStr for History {
+    type Err = StatsError;
+
+    /// Parses a history file's records, one per line. Blank lines and lines starting with `#` are
+    /// ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut history = History::new();
+        for (i, text) in s.lines().enumerate() {
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let record = Record::parse(text).ok_or_else(|| StatsError::Record {
+                line: i + 1,
+                text: text.to_owned(),
+            })?;
+            history.push(record);
+        }
+
+        Ok(history)
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for record in &self.records {
+            writeln!(f, "{record}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Statistics computed from a player's games.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    played: usize,
+    won: usize,
+    current_streak: usize,
+    max_streak: usize,
+    /// The number of games won with each number of guesses, starting from 1.
+    distribution: Vec<usize>,
+}
+
+impl Stats {
+    /// Computes the statistics for some games as of a given date, which the latest daily puzzle
+    /// must be from (or from the day before) to continue the current streak.
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a Record>, today: Date) -> Self {
+        let mut stats = Stats::default();
+
+        // Whether each daily puzzle was won the first time it was played, in puzzle order.
+        let mut daily = BTreeMap::new();
+        for record in records {
+            stats.played += 1;
+
+            if record.puzzle == Puzzle::Daily {
+                daily.entry(record.date).or_insert(record.won());
+            }
+
+            let Some(score) = record.score else {
+                continue;
+            };
+
+            stats.won += 1;
+
+            if stats.distribution.len() < score {
+                stats.distribution.resize(score, 0);
+            }
+            stats.distribution[score - 1] += 1;
+        }
+
+        // Skipping a day's puzzle breaks the streak.
+        let mut last: Option<i64> = None;
+        for (date, won) in daily {
+            let puzzle = date.puzzle_number();
+            if !won || last.is_none_or(|last| puzzle != last + 1) {
+                stats.current_streak = 0;
+            }
+            if won {
+                stats.current_streak += 1;
+                stats.max_streak = stats.max_streak.max(stats.current_streak);
+            }
+            last = Some(puzzle);
+        }
+
+        if last.is_none_or(|last| last < today.puzzle_number() - 1) {
+            stats.current_streak = 0;
+        }
+
+        stats
+    }
+
+    #[must_use]
+    pub fn played(&self) -> usize {
+        self.played
+    }
+
+    #[must_use]
+    pub fn won(&self) -> usize {
+        self.won
+    }
+
+    /// Returns the percentage of games that were won, rounded to the nearest whole number (or 0 if
+    /// no games were played).
+    #[must_use]
+    pub fn win_percentage(&self) -> usize {
+        if self.played == 0 {
+            return 0;
+        }
+
+        (self.won * 100 + self.played / 2) / self.played
+    }
+
+    /// Returns the number of daily puzzles won in a row, on consecutive dates up to today's or
+    /// yesterday's, since the last loss or skipped puzzle. Other games don't count towards
+    /// streaks, and replayed puzzles only count the first time.
+    #[must_use]
+    pub fn current_streak(&self) -> usize {
+        self.current_streak
+    }
+
+    /// Returns the most daily puzzles ever won in a row.
+    #[must_use]
+    pub fn max_streak(&self) -> usize {
+        self.max_streak
+    }
+
+    /// Returns the number of games won with each number of guesses: the first is the number of
+    /// games won with 1 guess, the second with 2, and so on up to the most guesses any win took.
+    #[must_use]
+    pub fn distribution(&self) -> &[usize] {
+        &self.distribution
+    }
+
+    /// Renders the statistics as a summary line followed by a histogram of the guess
+    /// distribution, like the one shown after finishing a game:
+    ///
+    /// ```text
+    /// Played  Win %  Current Streak  Max Streak
+    ///     12     92               4           7
+    ///
+    /// Guess Distribution
+    /// 1 ▏0
+    /// 2 ██ 1
+    /// 3 ██████████████████████████████ 6
+    /// ```
+    ///
+    /// The bar for `highlight` guesses (e.g. the score of the game just played) is drawn green.
+    #[must_use]
+    pub fn render(&self, highlight: Option<usize>) -> String {
+        let mut text = String::from("Played  Win %  Current Streak  Max Streak\n");
+        writeln!(
+            text,
+            "{:>6}  {:>5}  {:>14}  {:>10}",
+            self.played,
+            self.win_percentage(),
+            self.current_streak,
+            self.max_streak,
+        )
+        .expect("writing to a string can't fail");
+
+        text.push_str("\nGuess Distribution\n");
+        let rows = self.distribution.len().max(MIN_DISTRIBUTION_ROWS);
+        let label_width = rows.to_string().len();
+        let most = self.distribution.iter().copied().max().unwrap_or(0);
+        for guesses in 1..=rows {
+            let count = self.distribution.get(guesses - 1).copied().unwrap_or(0);
+            let bar = match count {
+                0 => "▏".to_owned(),
+                count => "█".repeat((count * BAR_WIDTH / most).max(1)),
+            };
+            let bar = if highlight == Some(guesses) {
+                bar.green().to_string()
+            } else {
+                bar
+            };
+
+            writeln!(text, "{guesses:>label_width$} {bar} {count}")
+                .expect("writing to a string can't fail");
+        }
+
+        text
+    }
+}
+
+/// Returns the path of the history file to record games in: `swordle/history.txt` in the user's
+/// data directory, which is `$XDG_DATA_HOME` or `~/.local/share`.
+///
+/// Returns `None` if neither `XDG_DATA_HOME` nor `HOME` is set to an absolute path.
+#[must_use]
+pub fn default_path() -> Option<PathBuf> {
+    data_dir(std::env::var_os("XDG_DATA_HOME"), std::env::var_os("HOME"))
+        .map(|dir| dir.join("swordle").join("history.txt"))
+}
+
+/// Returns the user's data directory, as defined by the XDG base directory specification, which
+/// ignores relative paths.
+fn data_dir(
+    xdg_data_home: Option<impl Into<PathBuf>>,
+    home: Option<impl Into<PathBuf>>,
+) -> Option<PathBuf> {
+    let absolute = |path: PathBuf| path.is_absolute().then_some(path);
+    xdg_data_home
+        .map(Into::into)
+        .and_then(absolute)
+        .or_else(|| {
+            home.map(Into::into)
+                .and_then(absolute)
+                .map(|home| home.join(".local/share"))
+        })
+}
+
+/// Errors that may occur when loading or saving a [`History`].
+#[derive(Debug, thiserror::Error)]
+pub enum StatsError {
+    /// Occurs when a line of a history file isn't a valid record.
+    #[error("line {line}: invalid record {text:?}")]
+    Record { line: usize, text: String },
+
+    /// Occurs when a history file can't be read or written.
+    #[error("Could not access history file: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Guess;
+    use crate::game::GameConfig;
+
+    fn outcome(solution: &str, guesses: &[&str], rules: GameConfig) -> GameOutcome {
+        let solution = Word::new(solution).unwrap();
+        let guesses = guesses
+            .iter()
+            .map(|g| Guess::new(&solution, Word::new(g).unwrap()))
+            .collect();
+
+        GameOutcome::with_config(solution, guesses, rules)
+    }
+
+    fn record(solution: &str, guesses: &[&str], choice: SolutionChoice) -> Record {
+        let rules = GameConfig::new().with_solution(choice);
+        Record::new(
+            &outcome(solution, guesses, rules),
+            Date::new(2025, 1, 2).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn history(text: &str) -> History {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let won = record(
+            "hunky",
+            &["salet", "courd", "hunky"],
+            SolutionChoice::Random,
+        );
+        assert_eq!(won.to_string(), "2025-01-02 hunky 3/6");
+        assert_eq!(won.score(), Some(3));
+
+        let rules = GameConfig::new().with_hard_mode(true).with_max_guesses(1);
+        let lost = outcome(
+            "hunky",
+            &["salet"],
+            rules.with_solution(SolutionChoice::Seeded(42)),
+        );
+        let lost = Record::new(&lost, Date::new(2025, 1, 2).unwrap()).unwrap();
+        assert_eq!(lost.to_string(), "2025-01-02 hunky X/1* seed:42");
+        assert!(!lost.won());
+
+        let daily = SolutionChoice::Daily(Date::new(2024, 12, 31).unwrap());
+        let daily = record("hunky", &["hunky"], daily);
+        assert_eq!(daily.to_string(), "2024-12-31 hunky 1/6 daily");
+        assert_eq!(daily.puzzle(), Puzzle::Daily);
+
+        let history = history(&format!("# comment\n{won}\n\n{lost}\n{daily}\n"));
+        assert_eq!(history.records(), [won, lost, daily]);
+        assert_eq!(history.to_string(), format!("{won}\n{lost}\n{daily}\n"));
+        assert_eq!(
+            self::history("2025-01-02 niño 4/∞ random").records()[0].score(),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn chosen_solutions_are_not_recorded() {
+        let hunky = Word::new("hunky").unwrap();
+        let rules = GameConfig::new().with_solution(SolutionChoice::Fixed(hunky));
+        let outcome = outcome("hunky", &["hunky"], rules);
+        assert_eq!(Record::new(&outcome, Date::new(2025, 1, 2).unwrap()), None);
+    }
+
+    #[test]
+    fn replays_are_the_same_game() {
+        let daily = SolutionChoice::Daily(Date::new(2025, 1, 1).unwrap());
+        let history = history(&format!(
+            "{}\n{}\n{}\n",
+            record("crane", &["crane"], daily),
+            record("hunky", &["hunky"], SolutionChoice::Seeded(42)),
+            record("lemon", &["lemon"], SolutionChoice::Random),
+        ));
+
+        assert!(history.contains_game(&record("crane", &["salet", "crane"], daily)));
+        assert!(history.contains_game(&record("hunky", &[], SolutionChoice::Seeded(42))));
+        assert!(!history.contains_game(&record("hunky", &[], SolutionChoice::Seeded(7))));
+        assert!(!history.contains_game(&record("lemon", &["lemon"], SolutionChoice::Random)));
+    }
+
+    #[test]
+    fn invalid_records_are_rejected() {
+        for line in [
+            "2025-01-02 hunky",
+            "2025-02-30 hunky 3/6",
+            "2025-01-02 hunky 7/6",
+            "2025-01-02 hunky 0/∞",
+            "2025-01-02 hunky X/∞",
+            "2025-01-02 hunky 3/6 extra",
+            "2025-01-02 hunky 3/6 seed:",
+            "2025-01-02 hunky 3/6 daily extra",
+        ] {
+            let error = format!("\n{line}").parse::<History>().unwrap_err();
+            assert!(
+                matches!(error, StatsError::Record { line: 2, .. }),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn stats_are_computed() {
+        let history = history(
+            "2025-01-01 crane 3/6 daily\n\
+             2025-01-02 hunky 4/6 daily\n\
+             2025-01-02 lemon X/6\n\
+             2025-01-03 those 4/6 daily\n\
+             2025-01-03 emcee 8/∞\n",
+        );
+        let today = Date::new(2025, 1, 4).unwrap();
+        let stats = history.stats(today);
+
+        assert_eq!(stats.played(), 5);
+        assert_eq!(stats.won(), 4);
+        assert_eq!(stats.win_percentage(), 80);
+        assert_eq!(stats.current_streak(), 3);
+        assert_eq!(stats.max_streak(), 3);
+        assert_eq!(stats.distribution(), [0, 0, 1, 2, 0, 0, 0, 1]);
+
+        let stats = history.stats_with_rules(today, None, false);
+        assert_eq!(stats.played(), 1);
+        assert_eq!(stats.distribution(), [0, 0, 0, 0, 0, 0, 0, 1]);
+
+        assert_eq!(Stats::default().win_percentage(), 0);
+        let stats = self::history("2025-01-01 crane 1/6 daily\n2025-01-02 hunky X/6 daily\n");
+        assert_eq!(stats.stats(today).current_streak(), 0);
+        assert_eq!(stats.stats(today).max_streak(), 1);
+    }
+
+    #[test]
+    fn streaks_follow_puzzle_dates() {
+        let today = Date::new(2025, 1, 4).unwrap();
+        let streaks = |text: &str| {
+            let stats = history(text).stats(today);
+            (stats.current_streak(), stats.max_streak())
+        };
+
+        // Skipping a day breaks the streak.
+        assert_eq!(
+            streaks("2025-01-01 crane 1/6 daily\n2025-01-03 hunky 2/6 daily\n"),
+            (1, 1)
+        );
+
+        // Older puzzles played later still join the streak, and replays don't count twice.
+        assert_eq!(
+            streaks(
+                "2025-01-03 crane 1/6 daily\n\
+                 2025-01-01 hunky 2/6 daily\n\
+                 2025-01-02 lemon 3/6 daily\n\
+                 2025-01-02 lemon X/6 daily\n"
+            ),
+            (3, 3)
+        );
+
+        // The streak ends if neither today's nor yesterday's puzzle has been played.
+        assert_eq!(
+            streaks("2025-01-01 crane 1/6 daily\n2025-01-02 hunky 2/6 daily\n"),
+            (0, 2)
+        );
+        assert_eq!(streaks("2025-01-04 crane 1/6 daily\n"), (1, 1));
+        assert_eq!(streaks("2025-01-04 crane 1/6\n"), (0, 0));
+    }
+
+    #[test]
+    fn render_draws_a_histogram() {
+        let stats = history(
+            "2025-01-01 crane 2/6 daily\n\
+             2025-01-02 hunky 3/6 daily\n\
+             2025-01-03 lemon 3/6 daily\n",
+        );
+        assert_eq!(
+            stats.stats(Date::new(2025, 1, 3).unwrap()).render(None),
+            format!(
+                "Played  Win %  Current Streak  Max Streak\n\
+                 {:>6}  {:>5}  {:>14}  {:>10}\n\
+                 \n\
+                 Guess Distribution\n\
+                 1 ▏ 0\n\
+                 2 {} 1\n\
+                 3 {} 2\n\
+                 4 ▏ 0\n\
+                 5 ▏ 0\n\
+                 6 ▏ 0\n",
+                3,
+                100,
+                3,
+                3,
+                "█".repeat(BAR_WIDTH / 2),
+                "█".repeat(BAR_WIDTH),
+            )
+        );
+    }
+
+    #[test]
+    fn histories_are_saved() {
+        let dir = std::env::temp_dir().join(format!("swordle-stats-{}", std::process::id()));
+        let path = dir.join("nested").join("history.txt");
+        assert_eq!(History::load(&path).unwrap(), History::new());
+
+        let record = record("hunky", &["hunky"], SolutionChoice::Random);
+        History::append(&path, &record).unwrap();
+        History::append(&path, &record).unwrap();
+        assert_eq!(History::load(&path).unwrap().records(), [record, record]);
+
+        std::fs::write(&path, "not a record\n").unwrap();
+        assert!(matches!(
+            History::load(&path),
+            Err(StatsError::Record { line: 1, .. })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn data_dir_follows_xdg() {
+        let none = None::<PathBuf>;
+        assert_eq!(
+            data_dir(Some("/data"), Some("/home/me")),
+            Some(PathBuf::from("/data"))
+        );
+        assert_eq!(
+            data_dir(Some("relative"), Some("/home/me")),
+            Some(PathBuf::from("/home/me/.local/share"))
+        );
+        assert_eq!(
+            data_dir(none.clone(), Some("/home/me")),
+            Some(PathBuf::from("/home/me/.local/share"))
+        );
+        assert_eq!(data_dir(none.clone(), none), None);
+    }
+}
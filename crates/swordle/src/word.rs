@@ -1,15 +1,30 @@
-mod bank;
+pub mod bank;
 mod impls;
 
+use crate::{Alphabet, Dictionary, alphabet};
+
 #[cfg(test)]
 mod tests;
 
+/// The number of letters in a word in the classic game.
 pub const WORD_LENGTH: usize = 5;
 
+/// The most letters a word may have.
+pub const MAX_WORD_LENGTH: usize = 8;
+
 /// A sequence of letters that may be submitted as a guess for a game.
+///
+/// Letters may be from any [`Alphabet`], and are stored a byte each (see [`alphabet`]).
 #[must_use]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Word([u8; WORD_LENGTH]);
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Word {
+    /// The word's letters, padded with zeros. No letter is zero, so the padding marks its length
+    /// without taking up more space.
+    letters: [u8; MAX_WORD_LENGTH],
+    /// The word encoded as UTF-8 (which takes at most two bytes for each letter), padded with
+    /// zeros in the same way.
+    text: [u8; MAX_WORD_LENGTH * 2],
+}
 
 impl Word {
     /// Attempts to create a new word from a string, trimming whitespace and performing case
@@ -21,7 +36,7 @@ impl Word {
     /// from the provided string.
     /// ```
     pub fn new(word: &str) -> Result<Self, WordError> {
-        let word = Self::new_no_dict(word)?;
+        let word = Self::parse(word, Some(WORD_LENGTH), Alphabet::ENGLISH)?;
         if bank::contains(word) {
             Ok(word)
         } else {
@@ -29,40 +44,100 @@ impl Word {
         }
     }
 
+    /// Attempts to create a new word from a string, trimming whitespace and performing case
+    /// conversion, that must be in a given [`Dictionary`] (and so have its word length and
+    /// alphabet).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WordError`] when a syntactically valid `Word` of the dictionary's length that
+    /// is in the dictionary cannot be parsed from the provided string.
+    pub fn with_dictionary(word: &str, dictionary: &Dictionary) -> Result<Self, WordError> {
+        let word = Self::parse(word, Some(dictionary.word_length()), dictionary.alphabet())?;
+        if dictionary.contains(word) {
+            Ok(word)
+        } else {
+            Err(WordError::Bank)
+        }
+    }
+
     /// Generates a random word (for use as a solution in a game).
     pub fn random() -> Self {
         bank::random_solution()
     }
 
-    fn new_no_dict(word: &str) -> Result<Self, WordError> {
-        let bytes = word.trim().as_bytes();
-        validate_word_bytes(bytes)?;
+    /// Creates a word with a given number of letters from any letters that can be stored,
+    /// whether or not it's in a dictionary.
+    #[cfg(test)]
+    pub(crate) fn new_no_dict(word: &str, length: usize) -> Result<Self, WordError> {
+        Self::parse(word, Some(length), Alphabet::LATIN_1)
+    }
 
-        let mut arr = [0u8; WORD_LENGTH];
-        arr.copy_from_slice(bytes);
-        arr.make_ascii_lowercase();
+    /// Parses a word made of letters in an alphabet, trimming whitespace and performing case
+    /// conversion. Letters are counted as they're displayed, so accents typed as separate
+    /// combining characters don't count as extra letters.
+    ///
+    /// Without a `length`, the word may have any number of letters up to [`MAX_WORD_LENGTH`].
+    pub(crate) fn parse(
+        word: &str,
+        length: Option<usize>,
+        alphabet: &Alphabet,
+    ) -> Result<Self, WordError> {
+        let limit = length.unwrap_or(MAX_WORD_LENGTH);
+        if limit == 0 || limit > MAX_WORD_LENGTH {
+            return Err(WordError::Length(limit));
+        }
+
+        let mut letters = [0u8; MAX_WORD_LENGTH];
+        let mut len = 0;
+        for letter in alphabet::letters(word.trim()) {
+            // Invalid letters are reported before a wrong length, since a 5-letter word with an
+            // unsupported letter is more helpfully rejected for its letter.
+            if len == limit {
+                return Err(length.map_or(WordError::TooLong(limit), WordError::Length));
+            }
+
+            letters[len] = letter
+                .filter(|&l| alphabet.contains(l))
+                .ok_or(WordError::Letter)?;
+            len += 1;
+        }
 
-        Ok(Word(arr))
+        match length {
+            Some(length) if len != length => return Err(WordError::Length(length)),
+            None if len == 0 => return Err(WordError::Empty),
+            _ => {}
+        }
+
+        let mut text = [0u8; MAX_WORD_LENGTH * 2];
+        let mut text_len = 0;
+        for &letter in &letters[..len] {
+            text_len += char::from(letter).encode_utf8(&mut text[text_len..]).len();
+        }
+
+        Ok(Word { letters, text })
     }
 
     #[must_use]
     pub const fn as_str(&self) -> &str {
-        // Safety: safe because we validate ASCII at construction
-        unsafe { std::str::from_utf8_unchecked(&self.0) }
+        // Safety: safe because the text is encoded from chars at construction
+        unsafe { std::str::from_utf8_unchecked(self.text.split_at(padded_len(&self.text)).0) }
     }
 
+    /// Returns the word's letters, a byte each. These are only the same as the word's UTF-8
+    /// bytes for English words.
     #[must_use]
     pub const fn as_bytes(&self) -> &[u8] {
-        &self.0
+        self.letters.split_at(self.len()).0
     }
 
     pub fn chars(&self) -> impl Iterator<Item = char> {
-        self.0.iter().map(|&b| b as char)
+        self.as_bytes().iter().map(|&b| char::from(b))
     }
 
     #[must_use]
     pub const fn len(&self) -> usize {
-        WORD_LENGTH
+        padded_len(&self.letters)
     }
 
     #[must_use]
@@ -71,40 +146,38 @@ impl Word {
     }
 }
 
-fn validate_word_bytes(bytes: &[u8]) -> Result<(), WordError> {
-    // Could just do the length check first, but validating that the first few bytes are all
-    // ascii letters is usually a better user experience because it will report an invalid
-    // letter error instead of an invalid length error for 5-letter words with multi-byte
-    // graphemes in them.
-    if bytes
-        .iter()
-        .take(WORD_LENGTH)
-        .any(|b| !b.is_ascii_alphabetic())
-    {
-        return Err(WordError::Letter);
-    }
-
-    if bytes.len() != WORD_LENGTH {
-        return Err(WordError::Length);
+/// Returns the length of zero-padded bytes.
+const fn padded_len(bytes: &[u8]) -> usize {
+    let mut len = 0;
+    while len < bytes.len() && bytes[len] != 0 {
+        len += 1;
     }
-
-    Ok(())
+    len
 }
 
 /// Errors that may occur when constructing a [`Word`].
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum WordError {
     /// Occurs when attempting to construct a `Word` of an incorrect length.
-    #[error("Word must be exactly {WORD_LENGTH} letters")]
-    Length,
+    #[error("Word must be exactly {0} letters")]
+    Length(usize),
+
+    /// Occurs when attempting to construct a `Word` of any length with more than
+    /// [`MAX_WORD_LENGTH`] letters.
+    #[error("Word must be at most {0} letters")]
+    TooLong(usize),
+
+    /// Occurs when attempting to construct a `Word` of any length without any letters.
+    #[error("Word must not be empty")]
+    Empty,
 
-    /// Occurs when attempting to construct a `Word` from a string that contains non-acii and/or
-    /// non-alphabetic characters.
-    #[error("Word must only contain ascii letters")]
+    /// Occurs when attempting to construct a `Word` from a string that contains characters that
+    /// aren't letters in its alphabet.
+    #[error("Word must only contain letters from the alphabet")]
     Letter,
 
     /// Occurs when attempting to construct a `Word` that is syntactically valid, but is not a
-    /// real/recognized English word.
-    #[error("Word must be a valid english word in the word bank")]
+    /// real/recognized word in the word bank or dictionary.
+    #[error("Word must be a valid word in the dictionary")]
     Bank,
 }
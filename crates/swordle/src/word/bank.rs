@@ -1,41 +1,28 @@
-use crate::Word;
-use rand::seq::IndexedRandom as _;
-use std::sync::LazyLock;
+use crate::{Dictionary, Word};
+use std::sync::{Arc, LazyLock};
 
-static SOLUTIONS: LazyLock<Box<[Word]>> =
-    LazyLock::new(|| str_to_words(include_str!("../../assets/solutions.txt")));
-static GUESSES: LazyLock<Box<[Word]>> =
-    LazyLock::new(|| str_to_words(include_str!("../../assets/guesses.txt")));
+pub(crate) static STANDARD: LazyLock<Arc<Dictionary>> = LazyLock::new(|| {
+    let solutions = include_str!("../../assets/solutions.txt");
+    let guesses = include_str!("../../assets/guesses.txt");
+    let dictionary = Dictionary::new(solutions.lines(), guesses.lines());
+    Arc::new(dictionary.expect("bundled word lists are a valid dictionary"))
+});
 
 /// Returns whether a given word is a valid solution/guess in the bank/dictionary.
+#[must_use]
 pub fn contains(word: Word) -> bool {
-    [&SOLUTIONS, &GUESSES]
-        .iter()
-        .any(|c| c.binary_search(&word).is_ok())
+    Dictionary::standard().contains(word)
 }
 
 /// Returns a random word from the solutions bank.
 pub fn random_solution() -> Word {
-    let Some(solution) = SOLUTIONS.choose(&mut rand::rng()).copied() else {
-        unreachable!("Bank should always have a solution")
-    };
-
-    solution
-}
-
-fn str_to_words(s: &str) -> Box<[Word]> {
-    let mut words: Box<[Word]> = s
-        .lines()
-        .filter_map(|w| Word::new_no_dict(w).ok())
-        .collect();
-
-    words.sort_unstable();
-    words
+    Dictionary::standard().random_solution()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::word::WORD_LENGTH;
     use std::collections::HashSet;
 
     #[test]
@@ -43,7 +30,7 @@ mod tests {
         let cases: &[&str] = &["crane", "audio", "skill"];
 
         for input in cases {
-            let word = Word::new_no_dict(input).unwrap();
+            let word = Word::new_no_dict(input, WORD_LENGTH).unwrap();
             assert!(contains(word));
         }
     }
@@ -53,7 +40,7 @@ mod tests {
         let cases: &[&str] = &["adieu", "adios"];
 
         for input in cases {
-            let word = Word::new_no_dict(input).unwrap();
+            let word = Word::new_no_dict(input, WORD_LENGTH).unwrap();
             assert!(contains(word));
         }
     }
@@ -63,7 +50,7 @@ mod tests {
         let cases: &[&str] = &["scxla", "zzyzx"];
 
         for input in cases {
-            let word = Word::new_no_dict(input).unwrap();
+            let word = Word::new_no_dict(input, WORD_LENGTH).unwrap();
             assert!(!contains(word));
         }
     }
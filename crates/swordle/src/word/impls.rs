@@ -7,6 +7,12 @@ impl std::fmt::Display for Word {
     }
 }
 
+impl std::fmt::Debug for Word {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Word").field(&self.as_str()).finish()
+    }
+}
+
 impl std::hash::Hash for Word {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
@@ -17,7 +23,7 @@ impl std::ops::Index<usize> for Word {
     type Output = u8;
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+        &self.as_bytes()[index]
     }
 }
 
@@ -15,7 +15,7 @@ fn constructor_returns_letter_error_when_invalid_letter() {
 #[test]
 fn constructor_returns_length_error_when_invalid_len() {
     let test_cases = ["automobile", "ego", "", " base", "some\t"];
-    run_constructor_tests(&test_cases, &WordError::Length);
+    run_constructor_tests(&test_cases, &WordError::Length(WORD_LENGTH));
 }
 
 #[test]
@@ -23,3 +23,44 @@ fn constructor_returns_bank_error_when_invalid_word() {
     let test_cases = ["asdfg", "crune", "falst"];
     run_constructor_tests(&test_cases, &WordError::Bank);
 }
+
+#[test]
+fn words_of_any_supported_length_work() {
+    for input in ["ox", "cats", "planet", "Journey", "Absolute"] {
+        let word = Word::new_no_dict(input, input.len()).unwrap();
+        assert_eq!(word.len(), input.len());
+        assert_eq!(word.as_str(), input.to_ascii_lowercase());
+        assert_eq!(word.chars().count(), input.len());
+        assert_eq!(
+            word[input.len() - 1],
+            input.as_bytes()[input.len() - 1].to_ascii_lowercase()
+        );
+    }
+
+    let short = Word::new_no_dict("cat", 3).unwrap();
+    let long = Word::new_no_dict("cats", 4).unwrap();
+    assert_ne!(short, long);
+    assert!(short < long);
+}
+
+#[test]
+fn accented_letters_are_single_letters() {
+    let dictionary = Dictionary::new(["niño", "peña"], std::iter::empty::<&str>()).unwrap();
+
+    for input in ["niño", "NIÑO", "nin\u{303}o"] {
+        let word = Word::with_dictionary(input, &dictionary).unwrap();
+        assert_eq!(word.len(), 4);
+        assert_eq!(word.as_str(), "niño");
+        assert_eq!(word[2], b'\xf1');
+        assert_eq!(word.chars().nth(2), Some('ñ'));
+    }
+
+    assert_eq!(
+        Word::with_dictionary("ninos", &dictionary),
+        Err(WordError::Length(4))
+    );
+    assert_eq!(
+        Word::with_dictionary("naïf", &dictionary),
+        Err(WordError::Letter)
+    );
+}